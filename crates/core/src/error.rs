@@ -1,3 +1,5 @@
+use crate::model::auth_info::TokenPermission;
+use crate::model::ulist::UlistBuiltinLabel;
 use reqwest::StatusCode;
 use strum::EnumIs;
 
@@ -6,30 +8,94 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[non_exhaustive]
 #[derive(Debug, EnumIs, thiserror::Error)]
 pub enum Error {
+  #[error("Bad request: {message}")]
+  BadRequest { message: String },
+
+  #[error("Connection failed: {reason}")]
+  ConnectionFailed { reason: String },
+
   #[error("Client disconnected")]
   Disconnected,
 
   #[error("\"{0}\" is not a valid id")]
   InvalidId(String),
 
+  #[error("\"{0}\" is not a valid release date")]
+  InvalidReleaseDate(String),
+
+  #[error("Invalid token: {message}")]
+  InvalidToken { message: String },
+
+  #[error("{0} is not a valid vote, it must be from 10 to 100")]
+  InvalidVote(u8),
+
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+
   #[error("Failed to parse JSON: {0}")]
   Json(#[from] serde_json::Error),
 
+  #[error("Token is missing the \"{0}\" permission")]
+  MissingPermission(TokenPermission),
+
+  #[error("Not found")]
+  NotFound,
+
+  #[error("The \"{0}\" label is managed by VNDB and can't be changed")]
+  ReadOnlyLabel(UlistBuiltinLabel),
+
   #[error("{}", reqwest_error(*status, reason))]
   RequestFailed {
     status: Option<StatusCode>,
     reason: String,
   },
 
+  #[error("[{status}] Server error: {message}")]
+  ServerError { status: StatusCode, message: String },
+
+  #[error("Throttled: {message}")]
+  Throttled { message: String },
+
+  #[error("Request timed out")]
+  TimedOut,
+
   #[error("Unauthorized: token needed")]
   Unauthorized,
 }
 
+impl Error {
+  /// Maps an unsuccessful response to an error, keeping the message sent by the API.
+  ///
+  /// See: <https://api.vndb.org/kana#api-structure>
+  pub(crate) fn from_status(status: StatusCode, message: &str) -> Self {
+    let message = message.trim().to_owned();
+    match status {
+      StatusCode::BAD_REQUEST => Self::BadRequest { message },
+      StatusCode::UNAUTHORIZED => Self::InvalidToken { message },
+      StatusCode::NOT_FOUND => Self::NotFound,
+      StatusCode::TOO_MANY_REQUESTS => Self::Throttled { message },
+      _ if status.is_server_error() => Self::ServerError { status, message },
+      _ => Self::RequestFailed {
+        status: Some(status),
+        reason: message,
+      },
+    }
+  }
+}
+
 impl From<reqwest::Error> for Error {
   fn from(error: reqwest::Error) -> Self {
+    if error.is_timeout() {
+      return Self::TimedOut;
+    }
+
     let status = error.status();
     let reason = error.to_string();
-    Self::RequestFailed { status, reason }
+    if error.is_connect() {
+      Self::ConnectionFailed { reason }
+    } else {
+      Self::RequestFailed { status, reason }
+    }
   }
 }
 
@@ -0,0 +1,102 @@
+use super::{CacheEntry, CacheKey, CacheStore};
+use crate::http::Endpoint;
+use futures::future::BoxFuture;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+use tokio::fs;
+
+const EXTENSION: &str = "cache";
+
+/// Keeps responses on disk, one file per entry, so they survive restarts.
+///
+/// Each file starts with the expiration time, in seconds since the Unix epoch,
+/// followed by the raw response body.
+#[derive(Debug)]
+pub struct DiskCache {
+  dir: PathBuf,
+}
+
+impl DiskCache {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into() }
+  }
+
+  fn path(&self, key: &CacheKey) -> PathBuf {
+    self
+      .dir
+      .join(key.as_str())
+      .with_extension(EXTENSION)
+  }
+
+  // Removes every cache file whose key matches, leaving anything else in the directory alone.
+  async fn remove_where(&self, matches: impl Fn(&CacheKey) -> bool) {
+    let Ok(mut entries) = fs::read_dir(&self.dir).await else {
+      return;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+      let path = entry.path();
+      if let Some(key) = cache_key(&path)
+        && matches(&key)
+      {
+        let _ = fs::remove_file(path).await;
+      }
+    }
+  }
+}
+
+impl CacheStore for DiskCache {
+  fn get<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, Option<CacheEntry>> {
+    Box::pin(async move {
+      let bytes = fs::read(self.path(key)).await.ok()?;
+      let (expires_at, body) = bytes.split_first_chunk::<8>()?;
+      let expires_at = Duration::from_secs(u64::from_le_bytes(*expires_at));
+
+      Some(CacheEntry {
+        body: body.to_vec(),
+        expires_at: UNIX_EPOCH + expires_at,
+      })
+    })
+  }
+
+  fn insert(&self, key: CacheKey, entry: CacheEntry) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+      let expires_at = entry
+        .expires_at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+      let mut bytes = Vec::with_capacity(8 + entry.body.len());
+      bytes.extend_from_slice(&expires_at.to_le_bytes());
+      bytes.extend_from_slice(&entry.body);
+
+      if fs::create_dir_all(&self.dir).await.is_ok() {
+        let _ = fs::write(self.path(&key), bytes).await;
+      }
+    })
+  }
+
+  fn remove<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, ()> {
+    Box::pin(async move {
+      let _ = fs::remove_file(self.path(key)).await;
+    })
+  }
+
+  fn remove_endpoint(&self, endpoint: Endpoint) -> BoxFuture<'_, ()> {
+    Box::pin(self.remove_where(move |key| key.is_for(endpoint)))
+  }
+
+  fn clear(&self) -> BoxFuture<'_, ()> {
+    Box::pin(self.remove_where(|_| true))
+  }
+}
+
+fn cache_key(path: &Path) -> Option<CacheKey> {
+  if path.extension()? != EXTENSION {
+    return None;
+  }
+
+  let stem = path.file_stem()?.to_str()?;
+  Some(CacheKey(stem.to_owned()))
+}
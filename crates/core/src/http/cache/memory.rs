@@ -0,0 +1,125 @@
+use super::{CacheEntry, CacheKey, CacheStore};
+use crate::http::Endpoint;
+use futures::future::{self, BoxFuture};
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Keeps the most recently used responses in memory.
+#[derive(Debug)]
+pub struct MemoryCache {
+  capacity: NonZeroUsize,
+  state: Mutex<Lru>,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+  entries: HashMap<CacheKey, (CacheEntry, u64)>,
+  order: BTreeMap<u64, CacheKey>,
+  tick: u64,
+}
+
+impl MemoryCache {
+  pub fn new(capacity: NonZeroUsize) -> Self {
+    Self {
+      capacity,
+      state: Mutex::new(Lru::default()),
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.lock().entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.lock().entries.is_empty()
+  }
+
+  fn lock(&self) -> MutexGuard<'_, Lru> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl CacheStore for MemoryCache {
+  fn get<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, Option<CacheEntry>> {
+    Box::pin(future::ready(self.lock().get(key)))
+  }
+
+  fn insert(&self, key: CacheKey, entry: CacheEntry) -> BoxFuture<'_, ()> {
+    self.lock().insert(key, entry, self.capacity);
+    Box::pin(future::ready(()))
+  }
+
+  fn remove<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, ()> {
+    self.lock().remove(key);
+    Box::pin(future::ready(()))
+  }
+
+  fn remove_endpoint(&self, endpoint: Endpoint) -> BoxFuture<'_, ()> {
+    let mut lru = self.lock();
+    let stale = lru
+      .entries
+      .keys()
+      .filter(|key| key.is_for(endpoint))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    for key in &stale {
+      lru.remove(key);
+    }
+
+    Box::pin(future::ready(()))
+  }
+
+  fn clear(&self) -> BoxFuture<'_, ()> {
+    let mut lru = self.lock();
+    lru.entries.clear();
+    lru.order.clear();
+    Box::pin(future::ready(()))
+  }
+}
+
+impl Lru {
+  fn get(&mut self, key: &CacheKey) -> Option<CacheEntry> {
+    let tick = self.next_tick();
+    let (entry, last_used) = self.entries.get_mut(key)?;
+    let previous = std::mem::replace(last_used, tick);
+    let entry = entry.clone();
+
+    self.order.remove(&previous);
+    self.order.insert(tick, key.clone());
+    Some(entry)
+  }
+
+  fn insert(&mut self, key: CacheKey, entry: CacheEntry, capacity: NonZeroUsize) {
+    let tick = self.next_tick();
+    if let Some((_, previous)) = self
+      .entries
+      .insert(key.clone(), (entry, tick))
+    {
+      self.order.remove(&previous);
+    }
+
+    self.order.insert(tick, key);
+
+    while self.entries.len() > capacity.get()
+      && let Some((_, oldest)) = self.order.pop_first()
+    {
+      self.entries.remove(&oldest);
+    }
+  }
+
+  fn remove(&mut self, key: &CacheKey) {
+    if let Some((_, last_used)) = self.entries.remove(key) {
+      self.order.remove(&last_used);
+    }
+  }
+
+  fn next_tick(&mut self) -> u64 {
+    self.tick += 1;
+    self.tick
+  }
+}
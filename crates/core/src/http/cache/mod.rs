@@ -0,0 +1,163 @@
+mod disk;
+mod memory;
+
+use super::Endpoint;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::time::Duration;
+
+pub use disk::DiskCache;
+pub use memory::MemoryCache;
+
+const DEFAULT_TTL: Duration = Duration::from_hours(1);
+
+/// Where cached responses are kept.
+///
+/// Caching is best effort, so stores should not fail loudly:
+/// an entry that cannot be read is simply a miss.
+pub trait CacheStore: fmt::Debug + Send + Sync {
+  fn get<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, Option<CacheEntry>>;
+  fn insert(&self, key: CacheKey, entry: CacheEntry) -> BoxFuture<'_, ()>;
+  fn remove<'a>(&'a self, key: &'a CacheKey) -> BoxFuture<'a, ()>;
+  /// Removes every entry for the given endpoint, see [`CacheKey::is_for`].
+  fn remove_endpoint(&self, endpoint: Endpoint) -> BoxFuture<'_, ()>;
+  fn clear(&self) -> BoxFuture<'_, ()>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+  pub(crate) fn new(endpoint: Endpoint, hash: u64) -> Self {
+    Self(format!("{endpoint}-{hash:016x}"))
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// Whether this key belongs to a response from the given endpoint.
+  pub fn is_for(&self, endpoint: Endpoint) -> bool {
+    self
+      .0
+      .strip_prefix(&endpoint.to_string())
+      .is_some_and(|rest| rest.starts_with('-'))
+  }
+}
+
+impl fmt::Display for CacheKey {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+  pub body: Vec<u8>,
+  pub expires_at: SystemTime,
+}
+
+impl CacheEntry {
+  pub fn is_expired(&self) -> bool {
+    self.expires_at <= SystemTime::now()
+  }
+}
+
+/// Caches successful responses to read requests.
+///
+/// Entries are keyed on the endpoint, the query and the token, and expire after a TTL
+/// that can be set per endpoint. A TTL of zero disables caching for that endpoint.
+#[derive(Clone, Debug)]
+pub struct Cache {
+  store: Arc<dyn CacheStore>,
+  ttl: Duration,
+  endpoint_ttl: HashMap<Endpoint, Duration>,
+}
+
+impl Cache {
+  pub fn new(store: impl CacheStore + 'static) -> Self {
+    Self {
+      store: Arc::new(store),
+      ttl: DEFAULT_TTL,
+      endpoint_ttl: HashMap::from([(Endpoint::AuthInfo, Duration::ZERO)]),
+    }
+  }
+
+  /// An in-memory cache that keeps at most `capacity` responses.
+  pub fn memory(capacity: NonZeroUsize) -> Self {
+    Self::new(MemoryCache::new(capacity))
+  }
+
+  /// Default TTL for every endpoint without a TTL of its own.
+  #[must_use]
+  pub fn ttl(mut self, ttl: Duration) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  #[must_use]
+  pub fn endpoint_ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+    self.endpoint_ttl.insert(endpoint, ttl);
+    self
+  }
+
+  pub async fn clear(&self) {
+    self.store.clear().await;
+  }
+
+  pub(crate) fn ttl_for(&self, endpoint: Endpoint) -> Duration {
+    self
+      .endpoint_ttl
+      .get(&endpoint)
+      .copied()
+      .unwrap_or(self.ttl)
+  }
+
+  pub(crate) async fn get(&self, key: &CacheKey) -> Option<Vec<u8>> {
+    let entry = self.store.get(key).await?;
+    if entry.is_expired() {
+      self.store.remove(key).await;
+      None
+    } else {
+      Some(entry.body)
+    }
+  }
+
+  pub(crate) async fn insert(&self, key: CacheKey, body: Vec<u8>, ttl: Duration) {
+    let expires_at = SystemTime::now() + ttl;
+    self
+      .store
+      .insert(key, CacheEntry { body, expires_at })
+      .await;
+  }
+
+  /// Evicts the responses made stale by a successful write to `endpoint`.
+  ///
+  /// Keys don't record whose list a response came from, so every cached list read is evicted.
+  pub(crate) async fn invalidate(&self, endpoint: Endpoint) {
+    let stale: &[Endpoint] = match endpoint {
+      // Release list entries are read through the `releases` field of `ulist`.
+      Endpoint::Ulist | Endpoint::Rlist => &[Endpoint::Ulist, Endpoint::UlistLabels],
+      _ => &[],
+    };
+
+    for endpoint in stale {
+      self.store.remove_endpoint(*endpoint).await;
+    }
+  }
+}
+
+/// How a single request interacts with the cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum CacheMode {
+  #[default]
+  Default,
+  /// Neither reads from nor writes to the cache.
+  Bypass,
+  /// Skips the cached entry, but stores the new response.
+  Refresh,
+}
@@ -0,0 +1,15 @@
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// FNV-1a, so fixture names and cache keys stay stable across Rust versions and platforms.
+pub(crate) fn fingerprint(parts: &[&[u8]]) -> u64 {
+  let mut hash = FNV_OFFSET;
+  for part in parts {
+    for byte in part.iter().chain(&[0xff]) {
+      hash ^= u64::from(*byte);
+      hash = hash.wrapping_mul(FNV_PRIME);
+    }
+  }
+
+  hash
+}
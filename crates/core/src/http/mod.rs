@@ -1,17 +1,39 @@
+mod cache;
+mod fingerprint;
 mod query;
+mod rate_limit;
 pub mod request;
-
-pub use query::{FieldSet, JsonQuery, JsonQueryBuilder, JsonQueryFilter, UrlQueryParams};
+mod retry;
+mod transport;
+
+pub(crate) use cache::CacheMode;
+pub use cache::{Cache, CacheEntry, CacheKey, CacheStore, DiskCache, MemoryCache};
+pub(crate) use fingerprint::fingerprint;
+pub use query::{
+  Comparison, Equality, FieldSet, JsonQuery, JsonQueryBuilder, JsonQueryFilter, UrlQueryParams,
+};
+pub(crate) use query::{FilterValue, combine_filters};
+pub use rate_limit::RateLimit;
+pub(crate) use rate_limit::RateLimiter;
 pub use request::API_BASE_URL;
+use request::DEFAULT_BASE_URL;
+pub use request::delete::Delete;
 pub use request::get::Get;
+pub use request::patch::Patch;
 pub use request::post::Post;
+pub use retry::RetryPolicy;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantArray};
+pub use transport::{
+  FixtureTransport, ReqwestTransport, Transport, TransportRequest, TransportResponse,
+};
 use url::Url;
 
 #[non_exhaustive]
 #[remain::sorted]
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Display, EnumString, VariantArray)]
+#[derive(
+  Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, Display, EnumString, VariantArray,
+)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum Endpoint {
   #[serde(rename = "authinfo")]
@@ -73,6 +95,22 @@ pub enum Endpoint {
 
 impl Endpoint {
   pub fn url(self) -> Url {
-    Url::parse(&format!("{API_BASE_URL}/{self}")).unwrap()
+    self.url_with_base(&DEFAULT_BASE_URL)
+  }
+
+  /// Builds the URL for this endpoint relative to the given base URL,
+  /// e.g. `http://127.0.0.1:8080/kana` becomes `http://127.0.0.1:8080/kana/vn`.
+  pub fn url_with_base(self, base: &Url) -> Url {
+    let mut url = base.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+
+    if let Ok(mut segments) = url.path_segments_mut() {
+      segments
+        .pop_if_empty()
+        .push(&self.to_string());
+    }
+
+    url
   }
 }
@@ -0,0 +1,102 @@
+use serde_json::Value as JsonValue;
+use strum::Display;
+
+/// Operators accepted by filters that can only be matched for equality.
+#[remain::sorted]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display)]
+pub enum Equality {
+  #[strum(serialize = "=")]
+  Eq,
+
+  #[strum(serialize = "!=")]
+  Ne,
+}
+
+/// Operators accepted by filters whose values can be ordered.
+#[remain::sorted]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display)]
+pub enum Comparison {
+  #[strum(serialize = "=")]
+  Eq,
+
+  #[strum(serialize = ">=")]
+  Ge,
+
+  #[strum(serialize = ">")]
+  Gt,
+
+  #[strum(serialize = "<=")]
+  Le,
+
+  #[strum(serialize = "<")]
+  Lt,
+
+  #[strum(serialize = "!=")]
+  Ne,
+}
+
+impl From<Equality> for Comparison {
+  fn from(op: Equality) -> Self {
+    match op {
+      Equality::Eq => Self::Eq,
+      Equality::Ne => Self::Ne,
+    }
+  }
+}
+
+/// How a value is written in a filter, which is not always how it is serialized.
+/// Numeric enums, for example, are sent as their code instead of their name.
+pub(crate) trait FilterValue {
+  fn to_filter_value(&self) -> JsonValue;
+}
+
+impl FilterValue for String {
+  fn to_filter_value(&self) -> JsonValue {
+    JsonValue::String(self.clone())
+  }
+}
+
+impl FilterValue for u8 {
+  fn to_filter_value(&self) -> JsonValue {
+    JsonValue::from(*self)
+  }
+}
+
+impl FilterValue for u16 {
+  fn to_filter_value(&self) -> JsonValue {
+    JsonValue::from(*self)
+  }
+}
+
+impl FilterValue for u32 {
+  fn to_filter_value(&self) -> JsonValue {
+    JsonValue::from(*self)
+  }
+}
+
+impl<A, B> FilterValue for (A, B)
+where
+  A: FilterValue,
+  B: FilterValue,
+{
+  fn to_filter_value(&self) -> JsonValue {
+    JsonValue::Array(vec![self.0.to_filter_value(), self.1.to_filter_value()])
+  }
+}
+
+impl<T: FilterValue> FilterValue for Box<T> {
+  fn to_filter_value(&self) -> JsonValue {
+    (**self).to_filter_value()
+  }
+}
+
+pub(crate) fn combine<T: FilterValue>(operator: &str, filters: &[T]) -> JsonValue {
+  let mut array = Vec::with_capacity(filters.len() + 1);
+  array.push(JsonValue::from(operator));
+  array.extend(
+    filters
+      .iter()
+      .map(FilterValue::to_filter_value),
+  );
+  JsonValue::Array(array)
+}
@@ -1,14 +1,18 @@
 use super::FieldSet;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::http::CacheMode;
 use crate::model::user::UserId;
-use crate::model::{QueryField, SortQueryBy};
+use crate::model::{QueryField, Response, SortQueryBy};
 use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::borrow::Cow;
 use std::num::NonZeroU16;
+use std::sync::Arc;
 
-type RequestFn<T> = Box<dyn FnOnce(JsonQuery) -> BoxFuture<'static, Result<T>> + Send>;
+type RequestFn<T> =
+  Arc<dyn Fn(JsonQuery, CacheMode) -> BoxFuture<'static, Result<T>> + Send + Sync>;
 
 #[remain::sorted]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -32,10 +36,13 @@ where
   Sort: SortQueryBy,
   Value: Serialize,
 {
+  cache_mode: CacheMode,
   compact_filters: bool,
   count: bool,
   fields: Option<FieldSet<Field>>,
   filters: JsonQueryFilter,
+  max_items: Option<usize>,
+  max_pages: Option<u16>,
   normalized_filters: bool,
   page: Option<NonZeroU16>,
   results: Option<u8>,
@@ -53,10 +60,13 @@ where
 {
   pub(in crate::http) fn new(send_request: RequestFn<Value>) -> Self {
     Self {
+      cache_mode: CacheMode::Default,
       compact_filters: false,
       count: false,
       fields: None,
       filters: JsonQueryFilter::default(),
+      max_items: None,
+      max_pages: None,
       normalized_filters: false,
       page: None,
       results: None,
@@ -97,6 +107,28 @@ where
     self
   }
 
+  /// Limits how many items [`stream`](Self::stream) yields. Errors don't count toward the limit.
+  #[must_use]
+  pub fn max_items(mut self, items: usize) -> Self {
+    self.max_items = Some(items);
+    self
+  }
+
+  /// Limits how many pages [`stream`](Self::stream) fetches.
+  /// With a limit of zero, nothing is fetched.
+  #[must_use]
+  pub fn max_pages(mut self, pages: u16) -> Self {
+    self.max_pages = Some(pages);
+    self
+  }
+
+  /// Sends the query without reading from or writing to the cache.
+  #[must_use]
+  pub fn no_cache(mut self) -> Self {
+    self.cache_mode = CacheMode::Bypass;
+    self
+  }
+
   #[must_use]
   pub fn normalized_filters(mut self) -> Self {
     self.normalized_filters = true;
@@ -128,6 +160,13 @@ where
     self
   }
 
+  /// Ignores any cached response, but caches the new one.
+  #[must_use]
+  pub fn refresh_cache(mut self) -> Self {
+    self.cache_mode = CacheMode::Refresh;
+    self
+  }
+
   #[must_use]
   pub fn results(mut self, results: u8) -> Self {
     self.results = Some(results.min(100));
@@ -153,20 +192,116 @@ where
   }
 
   pub async fn send(self) -> Result<Value> {
-    let query = JsonQuery {
+    let query = self.to_query();
+    (self.send_request)(query, self.cache_mode).await
+  }
+
+  fn to_query(&self) -> JsonQuery {
+    JsonQuery {
       compact_filters: self.compact_filters,
       count: self.count,
-      fields: self.fields.map(FieldSet::join),
-      filters: self.filters.into_inner(),
+      fields: self.fields.clone().map(FieldSet::join),
+      filters: self.filters.clone().into_inner(),
       normalized_filters: self.normalized_filters,
       page: self.page,
       results: self.results,
       reverse: self.reverse,
-      sort: self.sort.map(|s| s.to_string()),
-      user: self.user,
+      sort: self.sort.as_ref().map(ToString::to_string),
+      user: self.user.clone(),
+    }
+  }
+}
+
+impl<Field, Sort, T> JsonQueryBuilder<Field, Sort, Response<T>>
+where
+  Field: QueryField,
+  Sort: SortQueryBy,
+  T: Serialize + Send + 'static,
+{
+  /// Lazily fetches the results page by page, starting from the current page,
+  /// until the API reports there are no more results or a limit is reached.
+  pub fn stream(self) -> BoxStream<'static, Result<T>> {
+    let mut query = self.to_query();
+    let first_page = query.page.map_or(1, NonZeroU16::get);
+    query.page = NonZeroU16::new(first_page);
+
+    if self.max_pages == Some(0) || self.max_items == Some(0) {
+      return stream::empty().boxed();
+    }
+
+    let send_request = self.send_request;
+    let cache_mode = self.cache_mode;
+    let last_page = self
+      .max_pages
+      .map(|pages| first_page.saturating_add(pages - 1));
+
+    let pages = stream::try_unfold(Some(query), move |query| {
+      let send_request = Arc::clone(&send_request);
+      async move {
+        let Some(mut query) = query else {
+          return Ok::<_, Error>(None);
+        };
+
+        let page = query.page.map_or(1, NonZeroU16::get);
+        let response = send_request(query.clone(), cache_mode).await?;
+
+        let has_next = response.more && page < u16::MAX && last_page.is_none_or(|last| page < last);
+
+        let next = has_next.then(|| {
+          query.page = NonZeroU16::new(page + 1);
+          query
+        });
+
+        Ok(Some((response.results, next)))
+      }
+    });
+
+    let items = pages
+      .map_ok(|results| stream::iter(results.into_iter().map(Ok::<T, Error>)))
+      .try_flatten()
+      .boxed();
+
+    let Some(max_items) = self.max_items else {
+      return items;
     };
 
-    (self.send_request)(query).await
+    // Stops before polling again, so reaching the limit never fetches another page.
+    stream::unfold((items, 0), move |(mut items, taken)| async move {
+      if taken >= max_items {
+        return None;
+      }
+
+      let item = items.next().await?;
+      let taken = taken + usize::from(item.is_ok());
+      Some((item, (items, taken)))
+    })
+    .boxed()
+  }
+}
+
+impl<Field, Sort, Value> Clone for JsonQueryBuilder<Field, Sort, Value>
+where
+  Field: QueryField,
+  Sort: SortQueryBy + Clone,
+  Value: Serialize,
+{
+  fn clone(&self) -> Self {
+    Self {
+      cache_mode: self.cache_mode,
+      compact_filters: self.compact_filters,
+      count: self.count,
+      fields: self.fields.clone(),
+      filters: self.filters.clone(),
+      max_items: self.max_items,
+      max_pages: self.max_pages,
+      normalized_filters: self.normalized_filters,
+      page: self.page,
+      results: self.results,
+      reverse: self.reverse,
+      send_request: Arc::clone(&self.send_request),
+      sort: self.sort.clone(),
+      user: self.user.clone(),
+    }
   }
 }
 
@@ -1,14 +1,17 @@
+mod filter;
 mod json;
 mod url;
 
 use crate::model::QueryField;
+pub use filter::{Comparison, Equality};
+pub(crate) use filter::{FilterValue, combine as combine_filters};
 use itertools::Itertools;
 pub use json::{JsonQuery, JsonQueryBuilder, JsonQueryFilter};
 use std::collections::HashSet;
 use std::marker::PhantomData;
 pub use url::UrlQueryParams;
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct FieldSet<T: QueryField> {
   inner: HashSet<String>,
   marker: PhantomData<T>,
@@ -52,6 +55,20 @@ impl<T: QueryField> FieldSet<T> {
     self.inner.extend(iter);
   }
 
+  /// Prefixes the fields of a related entity with the path to it, e.g. `va.character.name`.
+  pub(crate) fn nested<U: QueryField>(prefix: &str, fields: FieldSet<U>) -> Self {
+    let mut set = Self::with_capacity(fields.inner.len() + 1);
+    set.inner.insert(format!("{prefix}.id"));
+    set.inner.extend(
+      fields
+        .inner
+        .into_iter()
+        .map(|field| format!("{prefix}.{field}")),
+    );
+
+    set
+  }
+
   pub fn insert(&mut self, field: &T) {
     self.inner.insert(field.to_string());
   }
@@ -68,8 +85,14 @@ impl<T: QueryField> FieldSet<T> {
     self.inner.is_empty()
   }
 
+  /// Iterates over the field paths, in no particular order.
+  pub fn iter_raw(&self) -> impl Iterator<Item = &str> {
+    self.inner.iter().map(String::as_str)
+  }
+
+  /// Joins the fields in a stable order, so the same set always produces the same request.
   pub fn join(self) -> String {
-    self.inner.iter().join(",")
+    self.inner.iter().sorted().join(",")
   }
 
   pub fn remove(&mut self, field: &T) -> bool {
@@ -83,6 +106,23 @@ impl<T: QueryField> FieldSet<T> {
   pub fn shrink_to_fit(&mut self) {
     self.inner.shrink_to_fit();
   }
+
+  /// Adds every field of `fields` to this set.
+  #[must_use]
+  pub fn with(mut self, fields: impl Into<Self>) -> Self {
+    self.inner.extend(fields.into().inner);
+    self
+  }
+}
+
+// Derived `Clone` would require `T: Clone`, which fields don't need to be.
+impl<T: QueryField> Clone for FieldSet<T> {
+  fn clone(&self) -> Self {
+    Self {
+      inner: self.inner.clone(),
+      marker: PhantomData,
+    }
+  }
 }
 
 impl<T: QueryField> Default for FieldSet<T> {
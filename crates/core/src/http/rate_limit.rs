@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+use std::num::NonZeroU32;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use tokio::time::{Duration, Instant, sleep};
+
+const REQUESTS: NonZeroU32 = NonZeroU32::new(200).unwrap();
+const WINDOW: Duration = Duration::from_mins(5);
+const THROTTLE_PENALTY: Duration = Duration::from_mins(1);
+
+/// Sliding window limit on how many requests can be sent.
+///
+/// See: <https://api.vndb.org/kana#rate-limiting>
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+  pub(crate) requests: NonZeroU32,
+  pub(crate) window: Duration,
+  pub(crate) execution_time: Option<ExecutionTime>,
+  pub(crate) throttle_penalty: Duration,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ExecutionTime {
+  pub(crate) budget: Duration,
+  pub(crate) window: Duration,
+}
+
+impl RateLimit {
+  /// Allows up to `requests` requests in any `window`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `requests` is zero, as no request could ever be sent.
+  pub fn new(requests: u32, window: Duration) -> Self {
+    Self {
+      requests: NonZeroU32::new(requests).expect("rate limit must allow at least one request"),
+      window,
+      execution_time: None,
+      throttle_penalty: THROTTLE_PENALTY,
+    }
+  }
+
+  /// The request limit published by VNDB: 200 requests every 5 minutes.
+  pub fn vndb() -> Self {
+    Self::new(REQUESTS.get(), WINDOW)
+  }
+
+  /// Limits how long requests can take in any `window`, as the API also caps server execution time
+  /// at 1 second every minute.
+  ///
+  /// The server doesn't report its execution time, so the time until the response arrives is used
+  /// instead. That includes network latency, so the budget should be set well above what the server
+  /// allows, or ordinary traffic will be throttled.
+  #[must_use]
+  pub fn execution_time(mut self, budget: Duration, window: Duration) -> Self {
+    self.execution_time = Some(ExecutionTime { budget, window });
+    self
+  }
+
+  /// How long to stop sending requests after being throttled,
+  /// unless the response says otherwise through `Retry-After`.
+  #[must_use]
+  pub fn throttle_penalty(mut self, penalty: Duration) -> Self {
+    self.throttle_penalty = penalty;
+    self
+  }
+}
+
+impl Default for RateLimit {
+  fn default() -> Self {
+    Self::vndb()
+  }
+}
+
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+  limit: RateLimit,
+  state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+  sent: VecDeque<Instant>,
+  executed: VecDeque<(Instant, Duration)>,
+  paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+  pub(crate) fn new(limit: RateLimit) -> Self {
+    Self {
+      limit,
+      state: Mutex::new(RateLimiterState::default()),
+    }
+  }
+
+  /// Waits until a request can be sent without exceeding the limit.
+  pub(crate) async fn acquire(&self) {
+    loop {
+      let wait = {
+        let mut state = self.lock();
+        let now = Instant::now();
+        while state
+          .sent
+          .front()
+          .is_some_and(|sent| now.duration_since(*sent) >= self.limit.window)
+        {
+          state.sent.pop_front();
+        }
+
+        if let Some(execution) = self.limit.execution_time {
+          while state
+            .executed
+            .front()
+            .is_some_and(|(finished, _)| now.duration_since(*finished) >= execution.window)
+          {
+            state.executed.pop_front();
+          }
+        }
+
+        if let Some(until) = state.paused_until
+          && until > now
+        {
+          until - now
+        } else if let Some(until) = self.execution_exhausted_until(&state) {
+          until.saturating_duration_since(now)
+        } else if state.sent.len() < self.capacity() {
+          state.sent.push_back(now);
+          return;
+        } else {
+          let oldest = state.sent.front().copied().unwrap_or(now);
+          (oldest + self.limit.window).saturating_duration_since(now)
+        }
+      };
+
+      sleep(wait).await;
+    }
+  }
+
+  /// Records how long a request took, counting it against the execution time budget.
+  pub(crate) fn executed(&self, elapsed: Duration) {
+    if self.limit.execution_time.is_some() {
+      self
+        .lock()
+        .executed
+        .push_back((Instant::now(), elapsed));
+    }
+  }
+
+  /// Pauses every request after the API says we are being throttled.
+  pub(crate) fn throttled(&self, retry_after: Option<Duration>) {
+    let penalty = retry_after.unwrap_or(self.limit.throttle_penalty);
+    let until = Instant::now() + penalty;
+    let mut state = self.lock();
+    if state
+      .paused_until
+      .is_none_or(|paused| paused < until)
+    {
+      state.paused_until = Some(until);
+    }
+  }
+
+  // Once the budget is spent, waits until the oldest request leaves the window.
+  fn execution_exhausted_until(&self, state: &RateLimiterState) -> Option<Instant> {
+    let execution = self.limit.execution_time?;
+    let spent: Duration = state
+      .executed
+      .iter()
+      .map(|(_, elapsed)| *elapsed)
+      .sum();
+
+    if spent < execution.budget {
+      return None;
+    }
+
+    let (oldest, _) = state.executed.front()?;
+    Some(*oldest + execution.window)
+  }
+
+  fn capacity(&self) -> usize {
+    usize::try_from(self.limit.requests.get()).unwrap_or(usize::MAX)
+  }
+
+  fn lock(&self) -> MutexGuard<'_, RateLimiterState> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+  }
+}
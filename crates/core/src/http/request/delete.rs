@@ -0,0 +1,62 @@
+use super::request;
+use crate::error::Result;
+use crate::http::Endpoint;
+use crate::make_request;
+use crate::model::prelude::*;
+use crate::vndb::Vndb;
+use http::Method;
+use std::sync::Weak;
+
+pub struct Delete {
+  vndb: Weak<Vndb>,
+}
+
+impl Delete {
+  pub fn new(vndb: Weak<Vndb>) -> Self {
+    Self { vndb }
+  }
+
+  /// Removes a visual novel from the list of the token owner.
+  /// Requires the [`ListWrite`](TokenPermission::ListWrite) permission.
+  pub async fn ulist(&self, id: &VisualNovelId) -> Result<()> {
+    let vndb = Vndb::upgrade(&self.vndb)?;
+    vndb
+      .require_permission(TokenPermission::ListWrite)
+      .await?;
+
+    let path = id.to_string();
+    make_request!(
+      vndb,
+      request::<()>(Endpoint::Ulist)
+        .method(Method::DELETE)
+        .path(&path)
+    )?;
+
+    Ok(())
+  }
+
+  /// Removes a release from the list of the token owner.
+  /// Requires the [`ListWrite`](TokenPermission::ListWrite) permission.
+  pub async fn rlist(&self, id: &ReleaseId) -> Result<()> {
+    let vndb = Vndb::upgrade(&self.vndb)?;
+    vndb
+      .require_permission(TokenPermission::ListWrite)
+      .await?;
+
+    let path = id.to_string();
+    make_request!(
+      vndb,
+      request::<()>(Endpoint::Rlist)
+        .method(Method::DELETE)
+        .path(&path)
+    )?;
+
+    Ok(())
+  }
+}
+
+impl Clone for Delete {
+  fn clone(&self) -> Self {
+    Self { vndb: Weak::clone(&self.vndb) }
+  }
+}
@@ -1,6 +1,8 @@
 use super::request_json;
 use crate::error::{Error, Result};
-use crate::http::{Endpoint, FieldSet, UrlQueryParams};
+use crate::http::{
+  Cache, CacheMode, Endpoint, FieldSet, RateLimiter, RetryPolicy, Transport, UrlQueryParams,
+};
 use crate::make_request;
 use crate::model::prelude::*;
 use crate::vndb::{Token, Vndb};
@@ -9,6 +11,7 @@ use serde::de::DeserializeOwned;
 use std::sync::Weak;
 use tokio::sync::Semaphore;
 use tokio::time::Duration;
+use url::Url;
 
 pub struct Get {
   vndb: Weak<Vndb>,
@@ -38,6 +41,22 @@ impl Get {
     make_request!(vndb, get_json(Endpoint::Stats))
   }
 
+  /// Labels in the list of the given user.
+  /// Private labels are only included if the token belongs to that user.
+  pub async fn ulist_labels<Field>(&self, user: &UserId, fields: Field) -> Result<UlistLabels>
+  where
+    Field: Into<FieldSet<UlistLabelField>>,
+  {
+    let mut query = UrlQueryParams(vec![("user", user.to_string())]);
+    let fields = fields.into();
+    if !fields.is_empty() {
+      query.extend(fields.into_url_query());
+    }
+
+    let vndb = Vndb::upgrade(&self.vndb)?;
+    make_request!(vndb, get_json(Endpoint::UlistLabels).query(query))
+  }
+
   /// Search for a user by their id or username.
   pub async fn user<UserQuery, Field>(&self, user: UserQuery, fields: Field) -> Result<Users>
   where
@@ -67,11 +86,17 @@ impl Clone for Get {
 async fn get_json<Json>(
   #[builder(start_fn)] endpoint: Endpoint,
   semaphore: Weak<Semaphore>,
+  transport: &dyn Transport,
+  base_url: &Url,
   query: Option<UrlQueryParams>,
   token: Option<&Token>,
   delay: Option<Duration>,
   timeout: Option<Duration>,
   user_agent: Option<&str>,
+  retry: Option<&RetryPolicy>,
+  rate_limiter: Option<&RateLimiter>,
+  cache: Option<&Cache>,
+  #[builder(default)] cache_mode: CacheMode,
 ) -> Result<Json>
 where
   Json: DeserializeOwned,
@@ -79,11 +104,17 @@ where
   request_json::<(), _>(endpoint)
     .method(Method::GET)
     .semaphore(semaphore)
+    .transport(transport)
+    .base_url(base_url)
     .maybe_query(query)
     .maybe_token(token)
     .maybe_delay(delay)
     .maybe_timeout(timeout)
     .maybe_user_agent(user_agent)
+    .maybe_retry(retry)
+    .maybe_rate_limiter(rate_limiter)
+    .maybe_cache(cache)
+    .cache_mode(cache_mode)
     .call()
     .await
 }
@@ -1,87 +1,170 @@
+pub mod delete;
 pub mod get;
+pub mod patch;
 pub mod post;
 
-use super::{Endpoint, UrlQueryParams};
+use super::{
+  Cache, CacheKey, CacheMode, Endpoint, RateLimiter, RetryPolicy, Transport, TransportRequest,
+  TransportResponse, UrlQueryParams, fingerprint,
+};
 use crate::error::{Error, Result};
 use crate::vndb::Token;
-use http::Method;
-use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
-use reqwest::{Client, Response as RawResponse};
+use http::header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER, USER_AGENT};
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::sync::{LazyLock, Weak};
+use std::time::SystemTime;
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio::task::spawn;
-use tokio::time::{Duration, sleep};
+use tokio::time::{Duration, Instant, sleep};
+use url::Url;
 
 pub const API_BASE_URL: &str = "https://api.vndb.org/kana";
-const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+pub(crate) static DEFAULT_BASE_URL: LazyLock<Url> =
+  LazyLock::new(|| Url::parse(API_BASE_URL).unwrap());
 
-static HTTP: LazyLock<Client> = LazyLock::new(|| {
-  Client::builder()
-    .use_rustls_tls()
-    .https_only(true)
-    .build()
-    .expect("failed to create http client")
-});
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 #[bon::builder]
 pub(super) async fn request<Body>(
   #[builder(start_fn)] endpoint: Endpoint,
   method: Method,
   semaphore: Weak<Semaphore>,
+  transport: &dyn Transport,
+  base_url: &Url,
+  path: Option<&str>,
   query: Option<UrlQueryParams>,
   body: Option<&Body>,
   token: Option<&Token>,
   delay: Option<Duration>,
   timeout: Option<Duration>,
   user_agent: Option<&str>,
-) -> Result<RawResponse>
+  retry: Option<&RetryPolicy>,
+  rate_limiter: Option<&RateLimiter>,
+  cache: Option<&Cache>,
+  #[builder(default)] cache_mode: CacheMode,
+) -> Result<TransportResponse>
 where
   Body: Serialize + ?Sized,
 {
-  let permit = semaphore
-    .upgrade()
-    .unwrap()
-    .acquire_owned()
-    .await
-    .map_err(|_| Error::Disconnected)?;
-
-  let mut url = endpoint.url();
+  let mut url = endpoint.url_with_base(base_url);
+  if let Some(path) = path
+    && let Ok(mut segments) = url.path_segments_mut()
+  {
+    segments.push(path);
+  }
+
   if let Some(query) = query {
     url.query_pairs_mut().extend_pairs(query.0);
   }
 
-  let mut request = HTTP.request(method, url);
-
-  if let Some(body) = body {
-    request = request.header(CONTENT_TYPE, "application/json");
-    request = request.json(body);
-  }
+  let mut headers = HeaderMap::new();
+  let user_agent = user_agent.unwrap_or(DEFAULT_USER_AGENT);
+  headers.insert(USER_AGENT, header_value(user_agent)?);
 
   if let Some(token) = token {
-    request = request.header(AUTHORIZATION, token.to_header());
+    headers.insert(AUTHORIZATION, header_value(&token.to_header())?);
   }
 
-  if let Some(timeout) = timeout {
-    request = request.timeout(timeout);
+  let body = body.map(serde_json::to_vec).transpose()?;
+  if body.is_some() {
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
   }
 
-  if let Some(user_agent) = user_agent {
-    request = request.header(USER_AGENT, user_agent);
-  } else {
-    request = request.header(USER_AGENT, DEFAULT_USER_AGENT);
-  }
+  let request = TransportRequest { method, url, headers, body, timeout };
 
-  let response = request.send().await?.error_for_status()?;
+  // Only reads are cached, and POST is how the API takes queries.
+  let is_read = request.method == Method::GET || request.method == Method::POST;
+  let write_cache = cache.filter(|_| !is_read);
+  let cache = cache.filter(|cache| {
+    cache_mode != CacheMode::Bypass && is_read && !cache.ttl_for(endpoint).is_zero()
+  });
 
-  if let Some(delay) = delay {
-    delay_drop(permit, delay);
-  } else {
-    drop(permit);
+  let key = cache.map(|_| cache_key(endpoint, &request));
+  if let Some(cache) = cache
+    && let Some(key) = &key
+    && cache_mode == CacheMode::Default
+    && let Some(body) = cache.get(key).await
+  {
+    return Ok(TransportResponse::cached(body));
   }
 
-  Ok(response)
+  let mut attempt: u8 = 1;
+  loop {
+    // Waiting for the rate limit must not hold a permit other requests could use.
+    if let Some(rate_limiter) = rate_limiter {
+      rate_limiter.acquire().await;
+    }
+
+    let permit = semaphore
+      .upgrade()
+      .ok_or(Error::Disconnected)?
+      .acquire_owned()
+      .await
+      .map_err(|_| Error::Disconnected)?;
+
+    let sent_at = Instant::now();
+    let response = transport.send(request.clone()).await;
+    if let Some(rate_limiter) = rate_limiter {
+      rate_limiter.executed(sent_at.elapsed());
+    }
+
+    let response = match response {
+      Ok(response) => response,
+      Err(err) => {
+        if let Some(retry) = retry
+          && attempt < retry.max_attempts.get()
+          && RetryPolicy::is_retryable_error(&err)
+        {
+          release(permit, delay);
+          sleep(retry.backoff(attempt, None)).await;
+          attempt += 1;
+          continue;
+        }
+
+        return Err(err);
+      }
+    };
+
+    if let Some(rate_limiter) = rate_limiter
+      && response.status == StatusCode::TOO_MANY_REQUESTS
+    {
+      rate_limiter.throttled(retry_after(&response));
+    }
+
+    if let Some(retry) = retry
+      && attempt < retry.max_attempts.get()
+      && retry.is_retryable(response.status)
+    {
+      let backoff = retry.backoff(attempt, retry_after(&response));
+      release(permit, delay);
+      sleep(backoff).await;
+      attempt += 1;
+      continue;
+    }
+
+    let status = response.status;
+    if status.is_client_error() || status.is_server_error() {
+      return Err(Error::from_status(status, &response.text()));
+    }
+
+    release(permit, delay);
+
+    if let Some(cache) = cache
+      && let Some(key) = key
+    {
+      cache
+        .insert(key, response.body.clone(), cache.ttl_for(endpoint))
+        .await;
+    }
+
+    if let Some(cache) = write_cache {
+      cache.invalidate(endpoint).await;
+    }
+
+    return Ok(response);
+  }
 }
 
 #[bon::builder]
@@ -89,12 +172,18 @@ pub(super) async fn request_json<Body, Json>(
   #[builder(start_fn)] endpoint: Endpoint,
   semaphore: Weak<Semaphore>,
   method: Method,
+  transport: &dyn Transport,
+  base_url: &Url,
   query: Option<UrlQueryParams>,
   body: Option<&Body>,
   token: Option<&Token>,
   delay: Option<Duration>,
   timeout: Option<Duration>,
   user_agent: Option<&str>,
+  retry: Option<&RetryPolicy>,
+  rate_limiter: Option<&RateLimiter>,
+  cache: Option<&Cache>,
+  #[builder(default)] cache_mode: CacheMode,
 ) -> Result<Json>
 where
   Body: Serialize + ?Sized,
@@ -103,17 +192,75 @@ where
   request(endpoint)
     .method(method)
     .semaphore(semaphore)
+    .transport(transport)
+    .base_url(base_url)
     .maybe_query(query)
     .maybe_body(body)
     .maybe_token(token)
     .maybe_delay(delay)
     .maybe_timeout(timeout)
     .maybe_user_agent(user_agent)
+    .maybe_retry(retry)
+    .maybe_rate_limiter(rate_limiter)
+    .maybe_cache(cache)
+    .cache_mode(cache_mode)
     .call()
     .await?
     .json()
-    .await
-    .map_err(Into::into)
+}
+
+// The token is part of the key, as some responses depend on who is asking.
+fn cache_key(endpoint: Endpoint, request: &TransportRequest) -> CacheKey {
+  let token = request
+    .headers
+    .get(AUTHORIZATION)
+    .map(HeaderValue::as_bytes)
+    .unwrap_or_default();
+
+  let hash = fingerprint(&[
+    request.method.as_str().as_bytes(),
+    request.url.as_str().as_bytes(),
+    request.body.as_deref().unwrap_or_default(),
+    token,
+  ]);
+
+  CacheKey::new(endpoint, hash)
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+  HeaderValue::from_str(value).map_err(|err| Error::RequestFailed {
+    status: None,
+    reason: err.to_string(),
+  })
+}
+
+// Reads `Retry-After`, which is either a number of seconds or an HTTP date.
+fn retry_after(response: &TransportResponse) -> Option<Duration> {
+  let value = response
+    .headers
+    .get(RETRY_AFTER)?
+    .to_str()
+    .ok()?
+    .trim();
+
+  if let Ok(seconds) = value.parse() {
+    return Some(Duration::from_secs(seconds));
+  }
+
+  let date = httpdate::parse_http_date(value).ok()?;
+  Some(
+    date
+      .duration_since(SystemTime::now())
+      .unwrap_or_default(),
+  )
+}
+
+fn release(permit: OwnedSemaphorePermit, delay: Option<Duration>) {
+  if let Some(delay) = delay {
+    delay_drop(permit, delay);
+  } else {
+    drop(permit);
+  }
 }
 
 fn delay_drop(permit: OwnedSemaphorePermit, duration: Duration) {
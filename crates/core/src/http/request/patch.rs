@@ -0,0 +1,66 @@
+use super::request;
+use crate::error::Result;
+use crate::http::Endpoint;
+use crate::make_request;
+use crate::model::prelude::*;
+use crate::vndb::Vndb;
+use http::Method;
+use serde_json::json;
+use std::sync::Weak;
+
+pub struct Patch {
+  vndb: Weak<Vndb>,
+}
+
+impl Patch {
+  pub fn new(vndb: Weak<Vndb>) -> Self {
+    Self { vndb }
+  }
+
+  /// Updates a visual novel in the list of the token owner, adding it if it is not there yet.
+  /// Requires the [`ListWrite`](TokenPermission::ListWrite) permission.
+  pub async fn ulist(&self, id: &VisualNovelId, update: &UlistUpdate) -> Result<()> {
+    let vndb = Vndb::upgrade(&self.vndb)?;
+    vndb
+      .require_permission(TokenPermission::ListWrite)
+      .await?;
+
+    let path = id.to_string();
+    make_request!(
+      vndb,
+      request(Endpoint::Ulist)
+        .method(Method::PATCH)
+        .path(&path)
+        .body(update)
+    )?;
+
+    Ok(())
+  }
+
+  /// Sets the status of a release in the list of the token owner, adding it if it is not there yet.
+  /// Requires the [`ListWrite`](TokenPermission::ListWrite) permission.
+  pub async fn rlist(&self, id: &ReleaseId, status: RlistStatus) -> Result<()> {
+    let vndb = Vndb::upgrade(&self.vndb)?;
+    vndb
+      .require_permission(TokenPermission::ListWrite)
+      .await?;
+
+    let path = id.to_string();
+    let body = json!({ "status": status.code() });
+    make_request!(
+      vndb,
+      request(Endpoint::Rlist)
+        .method(Method::PATCH)
+        .path(&path)
+        .body(&body)
+    )?;
+
+    Ok(())
+  }
+}
+
+impl Clone for Patch {
+  fn clone(&self) -> Self {
+    Self { vndb: Weak::clone(&self.vndb) }
+  }
+}
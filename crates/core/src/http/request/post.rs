@@ -1,20 +1,24 @@
 use super::request_json;
 use crate::error::Result;
-use crate::http::{Endpoint, JsonQueryBuilder as Query, UrlQueryParams};
+use crate::http::{
+  Cache, CacheMode, Endpoint, JsonQueryBuilder as Query, RateLimiter, RetryPolicy, Transport,
+  UrlQueryParams,
+};
 use crate::make_request;
 use crate::model::prelude::*;
 use crate::vndb::{Token, Vndb};
 use http::Method;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use std::sync::Weak;
+use std::sync::{Arc, Weak};
 use tokio::sync::Semaphore;
 use tokio::time::Duration;
+use url::Url;
 
 pub mod prelude {
   pub use super::{
     CharacterQuery, Post, ProducerQuery, ReleaseQuery, StaffQuery, TagQuery, TraitQuery,
-    VisualNovelQuery,
+    UlistQuery, VisualNovelQuery,
   };
 }
 
@@ -24,6 +28,7 @@ pub type ReleaseQuery = Query<ReleaseField, SortReleaseBy, Response<Release>>;
 pub type StaffQuery = Query<StaffField, SortStaffBy, Response<Staff>>;
 pub type TagQuery = Query<TagField, SortTagBy, Response<Tag>>;
 pub type TraitQuery = Query<TraitField, SortTraitBy, Response<Trait>>;
+pub type UlistQuery = Query<UlistField, SortUlistBy, Response<UlistEntry>>;
 pub type VisualNovelQuery = Query<VisualNovelField, SortVisualNovelBy, Response<VisualNovel>>;
 
 pub struct Post {
@@ -33,10 +38,14 @@ pub struct Post {
 macro_rules! post_request {
   ($self:expr, $kind:ident, $endpoint:ident) => {{
     let vndb = Weak::clone(&$self.vndb);
-    $kind::new(Box::new(move |query| {
+    $kind::new(Arc::new(move |query, cache_mode| {
+      let vndb = Weak::clone(&vndb);
       Box::pin(async move {
         let vndb = Vndb::upgrade(&vndb)?;
-        make_request!(vndb, post_json(Endpoint::$endpoint, &query))
+        make_request!(
+          vndb,
+          post_json(Endpoint::$endpoint, &query).cache_mode(cache_mode)
+        )
       })
     }))
   }};
@@ -71,6 +80,11 @@ impl Post {
     post_request!(self, TraitQuery, Trait)
   }
 
+  /// Visual novels in a user's list. The query must be given a [`user`](Query::user).
+  pub fn ulist(&self) -> UlistQuery {
+    post_request!(self, UlistQuery, Ulist)
+  }
+
   pub fn visual_novel(&self) -> VisualNovelQuery {
     post_request!(self, VisualNovelQuery, VisualNovel)
   }
@@ -88,10 +102,16 @@ async fn post_json<Body, Json>(
   #[builder(start_fn)] body: &Body,
   query: Option<UrlQueryParams>,
   semaphore: Weak<Semaphore>,
+  transport: &dyn Transport,
+  base_url: &Url,
   token: Option<&Token>,
   delay: Option<Duration>,
   timeout: Option<Duration>,
   user_agent: Option<&str>,
+  retry: Option<&RetryPolicy>,
+  rate_limiter: Option<&RateLimiter>,
+  cache: Option<&Cache>,
+  #[builder(default)] cache_mode: CacheMode,
 ) -> Result<Json>
 where
   Body: Serialize + ?Sized,
@@ -100,12 +120,18 @@ where
   request_json(endpoint)
     .method(Method::POST)
     .semaphore(semaphore)
+    .transport(transport)
+    .base_url(base_url)
     .body(body)
     .maybe_query(query)
     .maybe_token(token)
     .maybe_delay(delay)
     .maybe_timeout(timeout)
     .maybe_user_agent(user_agent)
+    .maybe_retry(retry)
+    .maybe_rate_limiter(rate_limiter)
+    .maybe_cache(cache)
+    .cache_mode(cache_mode)
     .call()
     .await
 }
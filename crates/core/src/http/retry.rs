@@ -0,0 +1,139 @@
+use crate::error::Error;
+use http::StatusCode;
+use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::num::NonZeroU8;
+use tokio::time::Duration;
+
+const MAX_ATTEMPTS: NonZeroU8 = NonZeroU8::new(3).unwrap();
+
+/// Controls how failed requests are retried.
+///
+/// Each attempt acquires its own permit, so retries still respect the client's concurrency limit.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+  pub(crate) max_attempts: NonZeroU8,
+  pub(crate) initial_backoff: Duration,
+  pub(crate) max_backoff: Duration,
+  pub(crate) jitter: bool,
+  pub(crate) respect_retry_after: bool,
+  pub(crate) statuses: HashSet<StatusCode>,
+}
+
+impl RetryPolicy {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Total number of attempts, including the first one.
+  /// Zero is treated as one, so the request is sent once and never retried.
+  #[must_use]
+  pub fn max_attempts(mut self, attempts: u8) -> Self {
+    self.max_attempts = NonZeroU8::new(attempts).unwrap_or(NonZeroU8::MIN);
+    self
+  }
+
+  #[must_use]
+  pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+    self.initial_backoff = backoff;
+    self
+  }
+
+  #[must_use]
+  pub fn max_backoff(mut self, backoff: Duration) -> Self {
+    self.max_backoff = backoff;
+    self
+  }
+
+  #[must_use]
+  pub fn jitter(mut self, jitter: bool) -> Self {
+    self.jitter = jitter;
+    self
+  }
+
+  /// Whether to wait for as long as the `Retry-After` header says, instead of the computed backoff.
+  /// The wait is still capped by [`max_backoff`](Self::max_backoff).
+  #[must_use]
+  pub fn respect_retry_after(mut self, respect: bool) -> Self {
+    self.respect_retry_after = respect;
+    self
+  }
+
+  #[must_use]
+  pub fn retry_on(mut self, status: StatusCode) -> Self {
+    self.statuses.insert(status);
+    self
+  }
+
+  /// Replaces the set of status codes that should be retried.
+  #[must_use]
+  pub fn statuses<I>(mut self, statuses: I) -> Self
+  where
+    I: IntoIterator<Item = StatusCode>,
+  {
+    self.statuses = statuses.into_iter().collect();
+    self
+  }
+
+  pub fn is_retryable(&self, status: StatusCode) -> bool {
+    self.statuses.contains(&status)
+  }
+
+  /// Connection failures and timeouts are retried regardless of the status codes.
+  pub(crate) fn is_retryable_error(error: &Error) -> bool {
+    error.is_connection_failed() || error.is_timed_out()
+  }
+
+  /// How long to wait before the next attempt, `attempt` being the one that just failed.
+  pub(crate) fn backoff(&self, attempt: u8, retry_after: Option<Duration>) -> Duration {
+    if self.respect_retry_after
+      && let Some(retry_after) = retry_after
+    {
+      return retry_after.min(self.max_backoff);
+    }
+
+    let exponent = u32::from(attempt.saturating_sub(1));
+    let backoff = self
+      .initial_backoff
+      .saturating_mul(2u32.saturating_pow(exponent))
+      .min(self.max_backoff);
+
+    if self.jitter {
+      with_jitter(backoff)
+    } else {
+      backoff
+    }
+  }
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self {
+      max_attempts: MAX_ATTEMPTS,
+      initial_backoff: Duration::from_millis(500),
+      max_backoff: Duration::from_secs(30),
+      jitter: true,
+      respect_retry_after: true,
+      statuses: HashSet::from([
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        StatusCode::BAD_GATEWAY,
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::GATEWAY_TIMEOUT,
+      ]),
+    }
+  }
+}
+
+// Keeps at least half of the backoff and randomizes the rest.
+fn with_jitter(backoff: Duration) -> Duration {
+  let half = backoff / 2;
+  let nanos = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+  if nanos == 0 {
+    return backoff;
+  }
+
+  let random = RandomState::new().hash_one(backoff);
+  half + Duration::from_nanos(random % nanos)
+}
@@ -0,0 +1,212 @@
+use super::{Transport, TransportRequest, TransportResponse};
+use crate::error::Result;
+use crate::http::fingerprint;
+use crate::http::request::DEFAULT_BASE_URL;
+use futures::future::BoxFuture;
+use http::header::{CONTENT_TYPE, RETRY_AFTER};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::{fmt, fs};
+use url::Url;
+
+/// Records request/response pairs to fixture files, or serves them back without touching the network.
+///
+/// Fixtures are keyed on the method, the path relative to the base URL, the query and the body of
+/// the request. The host and headers are left out, so the same fixtures work for any base URL and no
+/// token ever ends up on disk.
+pub struct FixtureTransport {
+  dir: PathBuf,
+  base_path: String,
+  mode: Mode,
+}
+
+enum Mode {
+  Record(Arc<dyn Transport>),
+  Replay,
+}
+
+impl FixtureTransport {
+  /// Sends every request through `inner` and saves the response to `dir`.
+  pub fn record(dir: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+    Self {
+      dir: dir.into(),
+      base_path: base_path(&DEFAULT_BASE_URL),
+      mode: Mode::Record(Arc::new(inner)),
+    }
+  }
+
+  /// Answers every request with a fixture previously saved to `dir`.
+  pub fn replay(dir: impl Into<PathBuf>) -> Self {
+    Self {
+      dir: dir.into(),
+      base_path: base_path(&DEFAULT_BASE_URL),
+      mode: Mode::Replay,
+    }
+  }
+
+  /// The base URL of the client using this transport, so only the part of the path after it
+  /// ends up in the fixture key. Defaults to [`API_BASE_URL`](crate::http::API_BASE_URL).
+  #[must_use]
+  pub fn base_url(mut self, url: &Url) -> Self {
+    self.base_path = base_path(url);
+    self
+  }
+
+  fn fixture_path(&self, request: &TransportRequest) -> PathBuf {
+    let method = request.method.as_str().to_ascii_lowercase();
+    let name = request
+      .url
+      .path_segments()
+      .and_then(|mut segments| segments.next_back())
+      .filter(|segment| !segment.is_empty())
+      .unwrap_or("index");
+
+    let path = self.relative_path(&request.url);
+    let body = request.body.as_deref().unwrap_or_default();
+    let hash = fingerprint(&[method.as_bytes(), path.as_bytes(), body]);
+    self
+      .dir
+      .join(format!("{method}-{name}-{hash:016x}.json"))
+  }
+
+  fn save(&self, request: &TransportRequest, response: &TransportResponse) -> Result<()> {
+    let headers = [CONTENT_TYPE, RETRY_AFTER]
+      .into_iter()
+      .filter_map(|name| {
+        let value = response.headers.get(&name)?.to_str().ok()?;
+        Some((name.to_string(), value.to_owned()))
+      })
+      .collect();
+
+    let fixture = Fixture {
+      request: FixtureRequest {
+        method: request.method.to_string(),
+        path: self.relative_path(&request.url),
+        body: request
+          .body
+          .as_deref()
+          .map(|body| String::from_utf8_lossy(body).into_owned()),
+      },
+      response: FixtureResponse {
+        status: response.status.as_u16(),
+        headers,
+        body: response.text(),
+      },
+    };
+
+    fs::create_dir_all(&self.dir)?;
+    let json = serde_json::to_string_pretty(&fixture)?;
+    fs::write(self.fixture_path(request), json)?;
+
+    Ok(())
+  }
+
+  fn load(&self, request: &TransportRequest) -> Result<TransportResponse> {
+    let path = self.fixture_path(request);
+    let json = fs::read_to_string(&path).map_err(|err| {
+      if err.kind() == ErrorKind::NotFound {
+        let message = format!(
+          "no fixture for {} {} at {}",
+          request.method,
+          self.relative_path(&request.url),
+          path.display()
+        );
+
+        IoError::new(ErrorKind::NotFound, message)
+      } else {
+        err
+      }
+    })?;
+
+    let fixture: Fixture = serde_json::from_str(&json)?;
+    let status = StatusCode::from_u16(fixture.response.status)
+      .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+
+    let mut headers = HeaderMap::new();
+    for (name, value) in fixture.response.headers {
+      if let Ok(name) = HeaderName::from_bytes(name.as_bytes())
+        && let Ok(value) = HeaderValue::from_str(&value)
+      {
+        headers.insert(name, value);
+      }
+    }
+
+    Ok(TransportResponse {
+      status,
+      headers,
+      body: fixture.response.body.into_bytes(),
+    })
+  }
+
+  // The path and query after the base URL, e.g. `/vn` for `https://api.vndb.org/kana/vn`.
+  fn relative_path(&self, url: &Url) -> String {
+    let path = url
+      .path()
+      .strip_prefix(&self.base_path)
+      .filter(|path| path.is_empty() || path.starts_with('/'))
+      .unwrap_or(url.path());
+
+    match url.query() {
+      Some(query) => format!("{path}?{query}"),
+      None => path.to_owned(),
+    }
+  }
+}
+
+impl Transport for FixtureTransport {
+  fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+    Box::pin(async move {
+      match &self.mode {
+        Mode::Record(inner) => {
+          let response = inner.send(request.clone()).await?;
+          self.save(&request, &response)?;
+          Ok(response)
+        }
+        Mode::Replay => self.load(&request),
+      }
+    })
+  }
+}
+
+impl fmt::Debug for FixtureTransport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mode = match self.mode {
+      Mode::Record(_) => "record",
+      Mode::Replay => "replay",
+    };
+
+    f.debug_struct("FixtureTransport")
+      .field("dir", &self.dir)
+      .field("base_path", &self.base_path)
+      .field("mode", &mode)
+      .finish()
+  }
+}
+
+#[derive(Deserialize, Serialize)]
+struct Fixture {
+  request: FixtureRequest,
+  response: FixtureResponse,
+}
+
+#[derive(Deserialize, Serialize)]
+struct FixtureRequest {
+  method: String,
+  path: String,
+  body: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct FixtureResponse {
+  status: u16,
+  headers: BTreeMap<String, String>,
+  body: String,
+}
+
+fn base_path(url: &Url) -> String {
+  url.path().trim_end_matches('/').to_owned()
+}
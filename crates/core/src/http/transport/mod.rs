@@ -0,0 +1,133 @@
+mod fixture;
+
+use crate::error::Result;
+use futures::future::BoxFuture;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::sync::LazyLock;
+use tokio::time::Duration;
+use url::{Host, Url};
+
+pub use fixture::FixtureTransport;
+
+static HTTP: LazyLock<Client> = LazyLock::new(|| {
+  Client::builder()
+    .use_rustls_tls()
+    .https_only(true)
+    .build()
+    .expect("failed to create http client")
+});
+
+// Only used for loopback addresses, and only when the client explicitly allows it.
+static HTTP_LOOPBACK: LazyLock<Client> = LazyLock::new(|| {
+  Client::builder()
+    .use_rustls_tls()
+    .build()
+    .expect("failed to create http client")
+});
+
+/// Sends requests built by the client and hands back the raw responses.
+///
+/// Retries, rate limiting and error mapping all happen before and after the transport,
+/// so an implementation only needs to move bytes around.
+pub trait Transport: fmt::Debug + Send + Sync {
+  fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>>;
+}
+
+#[derive(Clone, Debug)]
+pub struct TransportRequest {
+  pub method: Method,
+  pub url: Url,
+  pub headers: HeaderMap,
+  pub body: Option<Vec<u8>>,
+  pub timeout: Option<Duration>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransportResponse {
+  pub status: StatusCode,
+  pub headers: HeaderMap,
+  pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+  pub(crate) fn cached(body: Vec<u8>) -> Self {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Self {
+      status: StatusCode::OK,
+      headers,
+      body,
+    }
+  }
+
+  pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+    serde_json::from_slice(&self.body).map_err(Into::into)
+  }
+
+  pub fn text(&self) -> String {
+    String::from_utf8_lossy(&self.body).into_owned()
+  }
+}
+
+/// The default transport, backed by [`reqwest`].
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+  allow_http: bool,
+}
+
+impl ReqwestTransport {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Allows plain HTTP for loopback addresses. Any other host still requires HTTPS.
+  #[must_use]
+  pub fn allow_http(mut self, allow: bool) -> Self {
+    self.allow_http = allow;
+    self
+  }
+}
+
+impl Transport for ReqwestTransport {
+  fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+    Box::pin(async move {
+      let client = if self.allow_http && is_loopback(&request.url) {
+        &*HTTP_LOOPBACK
+      } else {
+        &*HTTP
+      };
+
+      let mut builder = client
+        .request(request.method, request.url)
+        .headers(request.headers);
+
+      if let Some(body) = request.body {
+        builder = builder.body(body);
+      }
+
+      if let Some(timeout) = request.timeout {
+        builder = builder.timeout(timeout);
+      }
+
+      let response = builder.send().await?;
+      let status = response.status();
+      let headers = response.headers().clone();
+      let body = response.bytes().await?.to_vec();
+
+      Ok(TransportResponse { status, headers, body })
+    })
+  }
+}
+
+fn is_loopback(url: &Url) -> bool {
+  match url.host() {
+    Some(Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+    Some(Host::Ipv4(ip)) => ip.is_loopback(),
+    Some(Host::Ipv6(ip)) => ip.is_loopback(),
+    None => false,
+  }
+}
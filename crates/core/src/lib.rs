@@ -8,4 +8,4 @@ mod vndb;
 
 pub use http::Endpoint;
 pub use model::prelude::*;
-pub use vndb::{Token, Vndb};
+pub use vndb::{Token, Vndb, VndbBuilder};
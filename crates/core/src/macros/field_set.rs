@@ -54,3 +54,23 @@ macro_rules! impl_into_field_set {
     }
   };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_nested_fields {
+  ($target:ident { $($name:ident => $prefix:literal: $field:ident),+ $(,)? }) => {
+    impl $target {
+      $(
+        #[doc = concat!("Fields of the nested `", $prefix, "` object.")]
+        #[doc = ""]
+        #[doc = concat!("`", $prefix, ".id` is always requested, as the nested model needs it.")]
+        pub fn $name<F>(fields: F) -> $crate::http::FieldSet<$target>
+        where
+          F: Into<$crate::http::FieldSet<$field>>,
+        {
+          $crate::http::FieldSet::nested($prefix, fields.into())
+        }
+      )+
+    }
+  };
+}
@@ -0,0 +1,73 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_filter {
+  (
+    $target:ident {
+      values: { $($value:ident => $value_name:literal),* $(,)? },
+      flags: { $($flag:ident => $flag_name:literal),* $(,)? } $(,)?
+    }
+  ) => {
+    impl $target {
+      /// Matches when every filter matches.
+      pub fn and<I>(filters: I) -> Self
+      where
+        I: IntoIterator<Item = Self>,
+      {
+        Self::And(filters.into_iter().collect())
+      }
+
+      /// Matches when at least one of the filters matches.
+      pub fn or<I>(filters: I) -> Self
+      where
+        I: IntoIterator<Item = Self>,
+      {
+        Self::Or(filters.into_iter().collect())
+      }
+
+      pub fn to_json(&self) -> serde_json::Value {
+        use $crate::http::FilterValue;
+        match self {
+          Self::And(filters) => $crate::http::combine_filters("and", filters),
+          Self::Or(filters) => $crate::http::combine_filters("or", filters),
+          $(
+            Self::$value(op, value) => {
+              serde_json::json!([$value_name, op.to_string(), value.to_filter_value()])
+            }
+          )*
+          $(
+            Self::$flag(matches) => {
+              let op = if *matches { "=" } else { "!=" };
+              serde_json::json!([$flag_name, op, 1])
+            }
+          )*
+        }
+      }
+    }
+
+    impl $crate::http::FilterValue for $target {
+      fn to_filter_value(&self) -> serde_json::Value {
+        self.to_json()
+      }
+    }
+
+    impl From<$target> for $crate::http::JsonQueryFilter {
+      fn from(filter: $target) -> Self {
+        Self::new(filter.to_json())
+      }
+    }
+  };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_filter_value {
+  ($($target:ident),+ $(,)?) => {
+    $(
+      impl $crate::http::FilterValue for $target {
+        fn to_filter_value(&self) -> serde_json::Value {
+          serde_json::Value::String(self.to_string())
+        }
+      }
+    )+
+  };
+}
@@ -1,5 +1,8 @@
 mod field_set;
+mod filter;
 mod id_newtype;
+mod numeric_enum;
+mod str_serde;
 mod string_set;
 
 #[doc(hidden)]
@@ -8,10 +11,15 @@ macro_rules! make_request {
   ($vndb:expr, $request:expr) => {{
     $request
       .semaphore(std::sync::Arc::downgrade(&$vndb.semaphore))
+      .transport(&*$vndb.transport)
+      .base_url(&$vndb.base_url)
       .maybe_token($vndb.token.as_ref())
       .maybe_delay($vndb.delay.clone())
       .maybe_timeout($vndb.timeout.clone())
       .maybe_user_agent($vndb.user_agent.as_deref())
+      .maybe_retry($vndb.retry.as_ref())
+      .maybe_rate_limiter($vndb.rate_limiter.as_ref())
+      .maybe_cache($vndb.cache.as_ref())
       .call()
       .await
   }};
@@ -0,0 +1,99 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_numeric_enum {
+  ($target:ident { $($code:literal => $variant:ident),+ $(,)? }) => {
+    $crate::impl_numeric_enum!(@common $target { $($code => $variant),+ });
+
+    impl $target {
+      /// Number used by the API to represent this value.
+      pub fn code(self) -> u8 {
+        match self {
+          $(Self::$variant => $code,)+
+        }
+      }
+    }
+
+    // The API sends the code, but the name is what gets serialized.
+    impl<'de> serde::Deserialize<'de> for $target {
+      fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+      where
+        D: serde::Deserializer<'de>,
+      {
+        use serde::de::Error;
+        match Self::deserialize_code(deserializer)? {
+          Ok(value) => Ok(value),
+          Err(code) => {
+            let message = format!("invalid {} code: {code}", stringify!($target));
+            Err(D::Error::custom(message))
+          }
+        }
+      }
+    }
+  };
+  ($target:ident { $($code:literal => $variant:ident),+ $(,)? }, $unknown:ident) => {
+    $crate::impl_numeric_enum!(@common $target { $($code => $variant),+ });
+
+    impl $target {
+      /// Number used by the API to represent this value.
+      /// Unknown codes are kept whole, even when they don't fit in a `u8`.
+      pub fn code(self) -> u64 {
+        match self {
+          $(Self::$variant => $code,)+
+          Self::$unknown(code) => code,
+        }
+      }
+    }
+
+    // The API sends the code, but the name is what gets serialized.
+    // Codes added by VNDB after this was written are kept as they are.
+    impl<'de> serde::Deserialize<'de> for $target {
+      fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+      where
+        D: serde::Deserializer<'de>,
+      {
+        Ok(Self::deserialize_code(deserializer)?.unwrap_or_else(Self::$unknown))
+      }
+    }
+  };
+  (@common $target:ident { $($code:literal => $variant:ident),+ }) => {
+    impl $target {
+      /// Returns `None` if the code is not one of the known values.
+      pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+          $($code => Some(Self::$variant),)+
+          _ => None,
+        }
+      }
+
+      /// Reads either a code or a variant name, returning the code if it is unknown.
+      fn deserialize_code<'de, D>(
+        deserializer: D,
+      ) -> std::result::Result<std::result::Result<Self, u64>, D::Error>
+      where
+        D: serde::Deserializer<'de>,
+      {
+        use serde::de::Error;
+        use $crate::model::NumericOrName;
+
+        match <NumericOrName as serde::Deserialize>::deserialize(deserializer)? {
+          NumericOrName::Code(code) => Ok(
+            u8::try_from(code)
+              .ok()
+              .and_then(Self::from_code)
+              .ok_or(code),
+          ),
+          NumericOrName::Name(name) => match name.as_str() {
+            $(stringify!($variant) => Ok(Ok(Self::$variant)),)+
+            _ => Err(D::Error::unknown_variant(&name, &[$(stringify!($variant)),+])),
+          },
+        }
+      }
+    }
+
+    impl $crate::http::FilterValue for $target {
+      fn to_filter_value(&self) -> serde_json::Value {
+        self.code().into()
+      }
+    }
+  };
+}
@@ -0,0 +1,30 @@
+/// Serializes with `Display` and deserializes with `FromStr`, for enums whose `Unknown` variant
+/// is the `#[strum(default)]` one. Values VNDB adds after an enum was written end up there,
+/// and are written back as they were received.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_str_serde {
+  ($($target:ident),+ $(,)?) => {
+    $(
+      impl serde::Serialize for $target {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+          S: serde::Serializer,
+        {
+          serializer.collect_str(self)
+        }
+      }
+
+      impl<'de> serde::Deserialize<'de> for $target {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+          D: serde::Deserializer<'de>,
+        {
+          use serde::de::Error;
+          let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+          value.parse().map_err(D::Error::custom)
+        }
+      }
+    )+
+  };
+}
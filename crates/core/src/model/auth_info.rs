@@ -12,7 +12,7 @@ pub struct AuthInfo {
 
 #[non_exhaustive]
 #[remain::sorted]
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, Display, EnumString)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum TokenPermission {
   #[serde(rename = "listread")]
@@ -22,4 +22,8 @@ pub enum TokenPermission {
   #[serde(rename = "listwrite")]
   #[strum(serialize = "listwrite")]
   ListWrite,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
@@ -1,8 +1,13 @@
-use super::release::Release;
-use super::r#trait::Trait;
-use super::visual_novel::VisualNovel;
+use super::release::{Release, ReleaseField};
+use super::spoiler::SpoilerLevel;
+use super::staff::StaffFilter;
+use super::r#trait::{Trait, TraitField, TraitId};
+use super::visual_novel::{VisualNovel, VisualNovelField, VisualNovelFilter};
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality, FieldSet};
+use crate::{
+  impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set, impl_nested_fields,
+};
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::result::Result as StdResult;
@@ -36,6 +41,23 @@ pub struct Character {
   pub weight: Option<u32>,
 }
 
+impl Character {
+  /// Removes the traits and visual novels that would spoil more than `max`,
+  /// along with the traits marked as lies.
+  #[must_use]
+  pub fn without_spoilers(mut self, max: SpoilerLevel) -> Self {
+    if let Some(traits) = &mut self.traits {
+      traits.retain(|t| t.is_visible(max));
+    }
+
+    if let Some(vns) = &mut self.vns {
+      vns.retain(|vn| vn.is_visible(max));
+    }
+
+    self
+  }
+}
+
 impl From<Character> for CharacterId {
   fn from(c: Character) -> Self {
     c.id
@@ -76,8 +98,17 @@ impl<'de> Deserialize<'de> for CharacterBirthday {
   where
     D: Deserializer<'de>,
   {
-    let date: [u32; 2] = Deserialize::deserialize(deserializer)?;
-    Ok(Self { day: date[1], month: date[0] })
+    // The API sends `[month, day]`, but the struct is what gets serialized.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Birthday {
+      Array([u32; 2]),
+      Struct { day: u32, month: u32 },
+    }
+
+    Ok(match Birthday::deserialize(deserializer)? {
+      Birthday::Array([month, day]) | Birthday::Struct { day, month } => Self { day, month },
+    })
   }
 }
 
@@ -95,8 +126,8 @@ impl<'de> Deserialize<'de> for CharacterGender {
     D: Deserializer<'de>,
   {
     type Value = Option<CharacterGenderValue>;
-    let array: [Value; 2] = Deserialize::deserialize(deserializer)?;
-    Ok(Self { apparent: array[0], real: array[1] })
+    let (apparent, real) = deserialize_pair::<D, Value>(deserializer)?;
+    Ok(Self { apparent, real })
   }
 }
 
@@ -135,6 +166,39 @@ pub struct CharacterImage {
   pub votecount: Option<u32>,
 }
 
+/// Role of a character in a visual novel.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum CharacterRole {
+  /// Only makes an appearance.
+  #[serde(rename = "appears")]
+  #[strum(serialize = "appears")]
+  Appears,
+
+  /// Protagonist.
+  #[serde(rename = "main")]
+  #[strum(serialize = "main")]
+  Main,
+
+  /// Main character.
+  #[serde(rename = "primary")]
+  #[strum(serialize = "primary")]
+  Primary,
+
+  /// Side character.
+  #[serde(rename = "side")]
+  #[strum(serialize = "side")]
+  Side,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
+}
+
 #[remain::sorted]
 #[derive(Clone, Debug, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -149,14 +213,14 @@ impl<'de> Deserialize<'de> for CharacterSex {
     D: Deserializer<'de>,
   {
     type Value = Option<CharacterSexValue>;
-    let array: [Value; 2] = Deserialize::deserialize(deserializer)?;
-    Ok(Self { apparent: array[0], real: array[1] })
+    let (apparent, real) = deserialize_pair::<D, Value>(deserializer)?;
+    Ok(Self { apparent, real })
   }
 }
 
 #[remain::sorted]
 #[derive(
-  Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
 )]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum CharacterSexValue {
@@ -175,6 +239,10 @@ pub enum CharacterSexValue {
   #[serde(rename = "n")]
   #[strum(serialize = "n")]
   None,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
 
 #[remain::sorted]
@@ -184,7 +252,18 @@ pub struct CharacterTrait {
   pub lie: Option<bool>,
   #[serde(flatten)]
   pub r#trait: Trait,
-  pub spoiler: Option<u8>,
+  pub spoiler: Option<SpoilerLevel>,
+}
+
+impl CharacterTrait {
+  /// Whether the trait can be shown without going over `max`.
+  /// Traits marked as lies are never shown, and those without a spoiler level always are.
+  pub fn is_visible(&self, max: SpoilerLevel) -> bool {
+    self.lie != Some(true)
+      && self
+        .spoiler
+        .is_none_or(|spoiler| spoiler <= max)
+  }
 }
 
 impl From<CharacterTrait> for Trait {
@@ -198,12 +277,21 @@ impl From<CharacterTrait> for Trait {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct CharacterVisualNovel {
   pub release: Option<Release>,
-  pub role: Option<String>,
-  pub spoiler: Option<u8>,
+  pub role: Option<CharacterRole>,
+  pub spoiler: Option<SpoilerLevel>,
   #[serde(flatten)]
   pub visual_novel: VisualNovel,
 }
 
+impl CharacterVisualNovel {
+  /// Whether the character's appearance in the visual novel can be shown without going over `max`.
+  pub fn is_visible(&self, max: SpoilerLevel) -> bool {
+    self
+      .spoiler
+      .is_none_or(|spoiler| spoiler <= max)
+  }
+}
+
 impl From<CharacterVisualNovel> for VisualNovel {
   fn from(cvn: CharacterVisualNovel) -> Self {
     cvn.visual_novel
@@ -332,6 +420,23 @@ impl QueryField for CharacterField {}
 
 impl_into_field_set!(CharacterField);
 
+impl_nested_fields!(CharacterField {
+  traits => "traits": TraitField,
+  vns => "vns": VisualNovelField,
+});
+
+impl CharacterField {
+  /// Fields of the release nested in `vns`.
+  ///
+  /// Both `vns.id` and `vns.release.id` are always requested, as the nested models need them.
+  pub fn vns_release<F>(fields: F) -> FieldSet<Self>
+  where
+    F: Into<FieldSet<ReleaseField>>,
+  {
+    FieldSet::nested("vns.release", fields.into()).with(Self::VisualNovelId)
+  }
+}
+
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString)]
@@ -351,3 +456,86 @@ pub enum SortCharacterBy {
 }
 
 impl SortQueryBy for SortCharacterBy {}
+
+/// See: <https://api.vndb.org/kana#character-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CharacterFilter {
+  Age(Comparison, u16),
+  And(Vec<Self>),
+  /// Month and day.
+  Birthday(Equality, (u8, u8)),
+  BloodType(Equality, String),
+  Bust(Comparison, u16),
+  Cup(Comparison, String),
+  /// Only matches traits applied directly to the character, not their parents.
+  DirectTrait(Equality, TraitId),
+  Gender(Equality, CharacterGenderValue),
+  GenderSpoiler(Equality, CharacterGenderValue),
+  Height(Comparison, u16),
+  Hips(Comparison, u16),
+  Id(Comparison, CharacterId),
+  Or(Vec<Self>),
+  Role(Equality, CharacterRole),
+  Search(Equality, String),
+  /// Voice actor.
+  Seiyuu(Equality, Box<StaffFilter>),
+  Sex(Equality, CharacterSexValue),
+  SexSpoiler(Equality, CharacterSexValue),
+  Trait(Equality, TraitId),
+  VisualNovel(Equality, Box<VisualNovelFilter>),
+  Waist(Comparison, u16),
+  Weight(Comparison, u16),
+}
+
+impl_filter!(CharacterFilter {
+  values: {
+    Age => "age",
+    Birthday => "birthday",
+    BloodType => "blood_type",
+    Bust => "bust",
+    Cup => "cup",
+    DirectTrait => "dtrait",
+    Gender => "gender",
+    GenderSpoiler => "gender_spoil",
+    Height => "height",
+    Hips => "hips",
+    Id => "id",
+    Role => "role",
+    Search => "search",
+    Seiyuu => "seiyuu",
+    Sex => "sex",
+    SexSpoiler => "sex_spoil",
+    Trait => "trait",
+    VisualNovel => "vn",
+    Waist => "waist",
+    Weight => "weight",
+  },
+  flags: {},
+});
+
+impl_filter_value!(
+  CharacterId,
+  CharacterGenderValue,
+  CharacterRole,
+  CharacterSexValue
+);
+
+/// The API sends `[apparent, real]`, but the struct is what gets serialized.
+fn deserialize_pair<'de, D, T>(deserializer: D) -> StdResult<(T, T), D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de>,
+{
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Pair<T> {
+    Array([T; 2]),
+    Struct { apparent: T, real: T },
+  }
+
+  Ok(match Pair::deserialize(deserializer)? {
+    Pair::Array([apparent, real]) | Pair::Struct { apparent, real } => (apparent, real),
+  })
+}
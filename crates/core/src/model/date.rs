@@ -0,0 +1,273 @@
+use crate::error::{Error, Result};
+use crate::impl_filter_value;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use strum::EnumIs;
+
+/// Date of a release, which may be only partially known.
+///
+/// Dates are ordered the same way VNDB orders them: a missing month or day sorts after
+/// every known one, and a date yet to be announced sorts after everything else.
+///
+/// The parts are private, so a date can only be built through the constructors that validate it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReleaseDate(Precision);
+
+#[remain::sorted]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIs)]
+enum Precision {
+  Full { year: u16, month: u8, day: u8 },
+  Month { year: u16, month: u8 },
+  Tba,
+  Year { year: u16 },
+}
+
+impl ReleaseDate {
+  /// To be announced.
+  pub const TBA: Self = Self(Precision::Tba);
+
+  const TBA_STR: &'static str = "TBA";
+  const UNKNOWN: u32 = 99;
+
+  /// Returns `None` if the month or the day is out of range.
+  pub fn from_ymd(year: u16, month: u8, day: u8) -> Option<Self> {
+    ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+      .then_some(Self(Precision::Full { year, month, day }))
+  }
+
+  /// Returns `None` if the month is out of range.
+  pub fn from_ym(year: u16, month: u8) -> Option<Self> {
+    (1..=12)
+      .contains(&month)
+      .then_some(Self(Precision::Month { year, month }))
+  }
+
+  pub fn from_y(year: u16) -> Self {
+    Self(Precision::Year { year })
+  }
+
+  /// Number used by VNDB to store the date, in the `YYYYMMDD` format.
+  /// Unknown parts are filled with `99`, and a date to be announced is `99999999`.
+  pub fn code(self) -> u32 {
+    let (year, month, day) = match self.0 {
+      Precision::Full { year, month, day } => (u32::from(year), u32::from(month), u32::from(day)),
+      Precision::Month { year, month } => (u32::from(year), u32::from(month), Self::UNKNOWN),
+      Precision::Tba => return 99_999_999,
+      Precision::Year { year } => (u32::from(year), Self::UNKNOWN, Self::UNKNOWN),
+    };
+
+    (year * 10_000) + (month * 100) + day
+  }
+
+  pub fn year(self) -> Option<u16> {
+    match self.0 {
+      Precision::Full { year, .. } | Precision::Month { year, .. } | Precision::Year { year } => {
+        Some(year)
+      }
+      Precision::Tba => None,
+    }
+  }
+
+  pub fn month(self) -> Option<u8> {
+    match self.0 {
+      Precision::Full { month, .. } | Precision::Month { month, .. } => Some(month),
+      Precision::Tba | Precision::Year { .. } => None,
+    }
+  }
+
+  pub fn day(self) -> Option<u8> {
+    match self.0 {
+      Precision::Full { day, .. } => Some(day),
+      _ => None,
+    }
+  }
+
+  /// Whether the year, month and day are all known.
+  pub fn is_full(self) -> bool {
+    self.0.is_full()
+  }
+
+  /// Whether only the year and month are known.
+  pub fn is_month(self) -> bool {
+    self.0.is_month()
+  }
+
+  pub fn is_tba(self) -> bool {
+    self.0.is_tba()
+  }
+
+  /// Whether only the year is known.
+  pub fn is_year(self) -> bool {
+    self.0.is_year()
+  }
+}
+
+impl PartialOrd for ReleaseDate {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for ReleaseDate {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.code().cmp(&other.code())
+  }
+}
+
+impl fmt::Display for ReleaseDate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.0 {
+      Precision::Full { year, month, day } => write!(f, "{year:04}-{month:02}-{day:02}"),
+      Precision::Month { year, month } => write!(f, "{year:04}-{month:02}"),
+      Precision::Tba => f.write_str(Self::TBA_STR),
+      Precision::Year { year } => write!(f, "{year:04}"),
+    }
+  }
+}
+
+impl FromStr for ReleaseDate {
+  type Err = Error;
+
+  fn from_str(date: &str) -> Result<Self> {
+    if date.eq_ignore_ascii_case(Self::TBA_STR) {
+      return Ok(Self::TBA);
+    }
+
+    let invalid = || Error::InvalidReleaseDate(date.to_owned());
+    let mut parts = date.splitn(3, '-');
+    let year = parse_part(parts.next(), 4).ok_or_else(invalid)?;
+    let Some(month) = parts.next() else {
+      return Ok(Self::from_y(year));
+    };
+
+    let month = parse_part(Some(month), 2).ok_or_else(invalid)?;
+    let month = u8::try_from(month).map_err(|_| invalid())?;
+    let Some(day) = parts.next() else {
+      return Self::from_ym(year, month).ok_or_else(invalid);
+    };
+
+    let day = parse_part(Some(day), 2).ok_or_else(invalid)?;
+    let day = u8::try_from(day).map_err(|_| invalid())?;
+    Self::from_ymd(year, month, day).ok_or_else(invalid)
+  }
+}
+
+impl TryFrom<&str> for ReleaseDate {
+  type Error = Error;
+
+  fn try_from(date: &str) -> Result<Self> {
+    date.parse()
+  }
+}
+
+impl Serialize for ReleaseDate {
+  fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for ReleaseDate {
+  fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let date = String::deserialize(deserializer)?;
+    date.parse().map_err(D::Error::custom)
+  }
+}
+
+/// Fails if the year is negative or doesn't fit the four digits VNDB uses.
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for ReleaseDate {
+  type Error = Error;
+
+  fn try_from(date: chrono::NaiveDate) -> Result<Self> {
+    use chrono::Datelike;
+    let invalid = || Error::InvalidReleaseDate(date.to_string());
+    let year = valid_year(date.year()).ok_or_else(invalid)?;
+    let month = u8::try_from(date.month()).map_err(|_| invalid())?;
+    let day = u8::try_from(date.day()).map_err(|_| invalid())?;
+    Self::from_ymd(year, month, day).ok_or_else(invalid)
+  }
+}
+
+/// Only full dates can be converted.
+#[cfg(feature = "chrono")]
+impl TryFrom<ReleaseDate> for chrono::NaiveDate {
+  type Error = Error;
+
+  fn try_from(date: ReleaseDate) -> Result<Self> {
+    let Precision::Full { year, month, day } = date.0 else {
+      return Err(Error::InvalidReleaseDate(date.to_string()));
+    };
+
+    chrono::NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day))
+      .ok_or_else(|| Error::InvalidReleaseDate(date.to_string()))
+  }
+}
+
+/// Fails if the year is negative or doesn't fit the four digits VNDB uses.
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for ReleaseDate {
+  type Error = Error;
+
+  fn try_from(date: time::Date) -> Result<Self> {
+    let invalid = || Error::InvalidReleaseDate(date.to_string());
+    let year = valid_year(date.year()).ok_or_else(invalid)?;
+    Self::from_ymd(year, u8::from(date.month()), date.day()).ok_or_else(invalid)
+  }
+}
+
+/// Only full dates can be converted.
+#[cfg(feature = "time")]
+impl TryFrom<ReleaseDate> for time::Date {
+  type Error = Error;
+
+  fn try_from(date: ReleaseDate) -> Result<Self> {
+    let invalid = || Error::InvalidReleaseDate(date.to_string());
+    let Precision::Full { year, month, day } = date.0 else {
+      return Err(invalid());
+    };
+
+    let month = time::Month::try_from(month).map_err(|_| invalid())?;
+    time::Date::from_calendar_date(i32::from(year), month, day).map_err(|_| invalid())
+  }
+}
+
+impl_filter_value!(ReleaseDate);
+
+fn parse_part(part: Option<&str>, len: usize) -> Option<u16> {
+  let part = part?;
+  if part.len() == len
+    && part
+      .bytes()
+      .all(|byte| byte.is_ascii_digit())
+  {
+    part.parse().ok()
+  } else {
+    None
+  }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn valid_year(year: i32) -> Option<u16> {
+  u16::try_from(year)
+    .ok()
+    .filter(|year| *year <= 9999)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+  match month {
+    2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
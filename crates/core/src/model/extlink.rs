@@ -0,0 +1,332 @@
+use crate::http::FilterValue;
+use crate::{impl_filter_value, impl_str_serde};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::fmt;
+use strum::{Display, EnumIs, EnumString};
+
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ExternalLink {
+  pub id: Option<ExtlinkId>,
+  pub label: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub name: Option<ExtlinkSite>,
+  pub url: Option<String>,
+}
+
+/// Sites VNDB links to.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum ExtlinkSite {
+  #[strum(serialize = "anidb")]
+  AniDb,
+
+  #[strum(serialize = "appstore")]
+  AppStore,
+
+  #[strum(serialize = "bilibili")]
+  Bilibili,
+
+  #[strum(serialize = "booth")]
+  Booth,
+
+  #[strum(serialize = "denpa")]
+  Denpa,
+
+  #[strum(serialize = "digiket")]
+  Digiket,
+
+  #[strum(serialize = "dlsite")]
+  Dlsite,
+
+  #[strum(serialize = "dmm")]
+  Dmm,
+
+  #[strum(serialize = "egs")]
+  ErogameScape,
+
+  #[strum(serialize = "egs_creator")]
+  ErogameScapeCreator,
+
+  #[strum(serialize = "fakku")]
+  Fakku,
+
+  #[strum(serialize = "fanbox")]
+  Fanbox,
+
+  #[strum(serialize = "fantia")]
+  Fantia,
+
+  #[strum(serialize = "freem")]
+  Freem,
+
+  #[strum(serialize = "getchu")]
+  Getchu,
+
+  #[strum(serialize = "getchudl")]
+  GetchuDl,
+
+  #[strum(serialize = "gog")]
+  Gog,
+
+  #[strum(serialize = "googplay")]
+  GooglePlay,
+
+  #[strum(serialize = "gyutto")]
+  Gyutto,
+
+  #[strum(serialize = "imdb")]
+  Imdb,
+
+  #[strum(serialize = "itch")]
+  Itch,
+
+  #[strum(serialize = "jastusa")]
+  JastUsa,
+
+  #[strum(serialize = "jlist")]
+  Jlist,
+
+  #[strum(serialize = "kofi")]
+  KoFi,
+
+  #[strum(serialize = "mg")]
+  MangaGamer,
+
+  #[strum(serialize = "melon")]
+  MelonBooks,
+
+  #[strum(serialize = "mobygames")]
+  MobyGames,
+
+  #[strum(serialize = "nintendo")]
+  Nintendo,
+
+  #[strum(serialize = "nutaku")]
+  Nutaku,
+
+  #[strum(serialize = "patreon")]
+  Patreon,
+
+  #[strum(serialize = "patreonp")]
+  PatreonPost,
+
+  #[strum(serialize = "pixiv")]
+  Pixiv,
+
+  #[strum(serialize = "playstation_eu")]
+  PlayStationEu,
+
+  #[strum(serialize = "playstation_hk")]
+  PlayStationHk,
+
+  #[strum(serialize = "playstation_jp")]
+  PlayStationJp,
+
+  #[strum(serialize = "playstation_na")]
+  PlayStationNa,
+
+  #[strum(serialize = "steam")]
+  Steam,
+
+  #[strum(serialize = "toranoana")]
+  Toranoana,
+
+  #[strum(serialize = "twitter")]
+  Twitter,
+
+  #[strum(default)]
+  Unknown(String),
+
+  #[strum(serialize = "vgmdb")]
+  Vgmdb,
+
+  #[strum(serialize = "website")]
+  Website,
+
+  #[strum(serialize = "wikidata")]
+  Wikidata,
+
+  #[strum(serialize = "youtube")]
+  YouTube,
+}
+
+impl ExtlinkSite {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::AniDb => "AniDB",
+      Self::AppStore => "App Store",
+      Self::Bilibili => "Bilibili",
+      Self::Booth => "BOOTH",
+      Self::Denpa => "Denpasoft",
+      Self::Digiket => "DiGiket",
+      Self::Dlsite => "DLsite",
+      Self::Dmm => "DMM",
+      Self::ErogameScape => "ErogameScape",
+      Self::ErogameScapeCreator => "ErogameScape (creator)",
+      Self::Fakku => "Fakku",
+      Self::Fanbox => "Pixiv Fanbox",
+      Self::Fantia => "Fantia",
+      Self::Freem => "Freem!",
+      Self::Getchu => "Getchu",
+      Self::GetchuDl => "DL.Getchu",
+      Self::Gog => "GOG",
+      Self::GooglePlay => "Google Play",
+      Self::Gyutto => "Gyutto",
+      Self::Imdb => "IMDb",
+      Self::Itch => "Itch.io",
+      Self::JastUsa => "JAST USA",
+      Self::Jlist => "J-List",
+      Self::KoFi => "Ko-fi",
+      Self::MangaGamer => "MangaGamer",
+      Self::MelonBooks => "Melonbooks",
+      Self::MobyGames => "MobyGames",
+      Self::Nintendo => "Nintendo",
+      Self::Nutaku => "Nutaku",
+      Self::Patreon => "Patreon",
+      Self::PatreonPost => "Patreon post",
+      Self::Pixiv => "Pixiv",
+      Self::PlayStationEu => "PlayStation Store (EU)",
+      Self::PlayStationHk => "PlayStation Store (HK)",
+      Self::PlayStationJp => "PlayStation Store (JP)",
+      Self::PlayStationNa => "PlayStation Store (NA)",
+      Self::Steam => "Steam",
+      Self::Toranoana => "Toranoana",
+      Self::Twitter => "Twitter",
+      Self::Vgmdb => "VGMdb",
+      Self::Website => "Official website",
+      Self::Wikidata => "Wikidata",
+      Self::YouTube => "YouTube",
+      Self::Unknown(name) => name,
+    }
+  }
+
+  /// Whether the site identifies its pages with a number rather than a string.
+  pub fn has_numeric_id(&self) -> bool {
+    matches!(
+      self,
+      Self::AniDb
+        | Self::AppStore
+        | Self::Bilibili
+        | Self::Booth
+        | Self::Digiket
+        | Self::ErogameScape
+        | Self::ErogameScapeCreator
+        | Self::Fantia
+        | Self::Freem
+        | Self::Getchu
+        | Self::GetchuDl
+        | Self::Gyutto
+        | Self::MangaGamer
+        | Self::MelonBooks
+        | Self::PatreonPost
+        | Self::Pixiv
+        | Self::Steam
+        | Self::Toranoana
+        | Self::Vgmdb
+        | Self::Wikidata
+    )
+  }
+
+  /// Parses an id for this site, such as one read from a store catalog.
+  /// Returns `None` if the site uses numeric ids and `id` is not a number.
+  pub fn parse_id(&self, id: &str) -> Option<ExtlinkId> {
+    if self.has_numeric_id() {
+      id.trim().parse().ok().map(ExtlinkId::Number)
+    } else {
+      Some(ExtlinkId::Text(id.to_owned()))
+    }
+  }
+}
+
+impl_str_serde!(ExtlinkSite);
+impl_filter_value!(ExtlinkSite);
+
+/// Id of a page on an external site.
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, EnumIs)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(untagged)]
+pub enum ExtlinkId {
+  Number(u64),
+  Text(String),
+}
+
+impl fmt::Display for ExtlinkId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Number(id) => write!(f, "{id}"),
+      Self::Text(id) => f.write_str(id),
+    }
+  }
+}
+
+impl From<u64> for ExtlinkId {
+  fn from(id: u64) -> Self {
+    Self::Number(id)
+  }
+}
+
+impl From<String> for ExtlinkId {
+  fn from(id: String) -> Self {
+    Self::Text(id)
+  }
+}
+
+impl From<&str> for ExtlinkId {
+  fn from(id: &str) -> Self {
+    Self::Text(id.to_owned())
+  }
+}
+
+impl FilterValue for ExtlinkId {
+  fn to_filter_value(&self) -> JsonValue {
+    match self {
+      Self::Number(id) => JsonValue::from(*id),
+      Self::Text(id) => JsonValue::from(id.as_str()),
+    }
+  }
+}
+
+/// Value of the `extlink` filter.
+///
+/// See: <https://api.vndb.org/kana#release-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExtlinkQuery {
+  /// Matches a specific page on the site.
+  Id(ExtlinkSite, ExtlinkId),
+  /// Matches anything with a link to the site.
+  Site(ExtlinkSite),
+  /// Matches a link by its URL.
+  Url(String),
+}
+
+impl From<ExtlinkSite> for ExtlinkQuery {
+  fn from(site: ExtlinkSite) -> Self {
+    Self::Site(site)
+  }
+}
+
+impl<T> From<(ExtlinkSite, T)> for ExtlinkQuery
+where
+  T: Into<ExtlinkId>,
+{
+  fn from((site, id): (ExtlinkSite, T)) -> Self {
+    Self::Id(site, id.into())
+  }
+}
+
+impl FilterValue for ExtlinkQuery {
+  fn to_filter_value(&self) -> JsonValue {
+    match self {
+      Self::Id(site, id) => JsonValue::Array(vec![site.to_filter_value(), id.to_filter_value()]),
+      Self::Site(site) => site.to_filter_value(),
+      Self::Url(url) => JsonValue::from(url.as_str()),
+    }
+  }
+}
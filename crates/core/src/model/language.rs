@@ -0,0 +1,353 @@
+use crate::{impl_filter_value, impl_str_serde};
+use strum::{Display, EnumIs, EnumString};
+
+/// Regional variants are kept apart, so `zh-Hans` and `zh-Hant` are different languages.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum Language {
+  #[strum(serialize = "ar")]
+  Arabic,
+
+  #[strum(serialize = "eu")]
+  Basque,
+
+  #[strum(serialize = "be")]
+  Belarusian,
+
+  #[strum(serialize = "bg")]
+  Bulgarian,
+
+  #[strum(serialize = "ca")]
+  Catalan,
+
+  #[strum(serialize = "ck")]
+  Cherokee,
+
+  #[strum(serialize = "zh")]
+  Chinese,
+
+  #[strum(serialize = "zh-Hans")]
+  ChineseSimplified,
+
+  #[strum(serialize = "zh-Hant")]
+  ChineseTraditional,
+
+  #[strum(serialize = "hr")]
+  Croatian,
+
+  #[strum(serialize = "cs")]
+  Czech,
+
+  #[strum(serialize = "da")]
+  Danish,
+
+  #[strum(serialize = "nl")]
+  Dutch,
+
+  #[strum(serialize = "en")]
+  English,
+
+  #[strum(serialize = "eo")]
+  Esperanto,
+
+  #[strum(serialize = "fi")]
+  Finnish,
+
+  #[strum(serialize = "fr")]
+  French,
+
+  #[strum(serialize = "de")]
+  German,
+
+  #[strum(serialize = "el")]
+  Greek,
+
+  #[strum(serialize = "he")]
+  Hebrew,
+
+  #[strum(serialize = "hi")]
+  Hindi,
+
+  #[strum(serialize = "hu")]
+  Hungarian,
+
+  #[strum(serialize = "id")]
+  Indonesian,
+
+  #[strum(serialize = "iu")]
+  Inuktitut,
+
+  #[strum(serialize = "ga")]
+  Irish,
+
+  #[strum(serialize = "it")]
+  Italian,
+
+  #[strum(serialize = "ja")]
+  Japanese,
+
+  #[strum(serialize = "kk")]
+  Kazakh,
+
+  #[strum(serialize = "ko")]
+  Korean,
+
+  #[strum(serialize = "la")]
+  Latin,
+
+  #[strum(serialize = "lv")]
+  Latvian,
+
+  #[strum(serialize = "lt")]
+  Lithuanian,
+
+  #[strum(serialize = "mk")]
+  Macedonian,
+
+  #[strum(serialize = "ms")]
+  Malay,
+
+  #[strum(serialize = "ne")]
+  Nepali,
+
+  #[strum(serialize = "no")]
+  Norwegian,
+
+  #[strum(serialize = "fa")]
+  Persian,
+
+  #[strum(serialize = "pl")]
+  Polish,
+
+  #[strum(serialize = "pt-br")]
+  PortugueseBrazil,
+
+  #[strum(serialize = "pt-pt")]
+  PortuguesePortugal,
+
+  #[strum(serialize = "ro")]
+  Romanian,
+
+  #[strum(serialize = "ru")]
+  Russian,
+
+  #[strum(serialize = "gd")]
+  ScottishGaelic,
+
+  #[strum(serialize = "sr")]
+  Serbian,
+
+  #[strum(serialize = "sk")]
+  Slovak,
+
+  #[strum(serialize = "sl")]
+  Slovene,
+
+  #[strum(serialize = "es")]
+  Spanish,
+
+  #[strum(serialize = "sv")]
+  Swedish,
+
+  #[strum(serialize = "ta")]
+  Tagalog,
+
+  #[strum(serialize = "th")]
+  Thai,
+
+  #[strum(serialize = "tr")]
+  Turkish,
+
+  #[strum(serialize = "uk")]
+  Ukrainian,
+
+  #[strum(default)]
+  Unknown(String),
+
+  #[strum(serialize = "ur")]
+  Urdu,
+
+  #[strum(serialize = "vi")]
+  Vietnamese,
+}
+
+impl Language {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Arabic => "Arabic",
+      Self::Basque => "Basque",
+      Self::Belarusian => "Belarusian",
+      Self::Bulgarian => "Bulgarian",
+      Self::Catalan => "Catalan",
+      Self::Cherokee => "Cherokee",
+      Self::Chinese => "Chinese",
+      Self::ChineseSimplified => "Chinese (simplified)",
+      Self::ChineseTraditional => "Chinese (traditional)",
+      Self::Croatian => "Croatian",
+      Self::Czech => "Czech",
+      Self::Danish => "Danish",
+      Self::Dutch => "Dutch",
+      Self::English => "English",
+      Self::Esperanto => "Esperanto",
+      Self::Finnish => "Finnish",
+      Self::French => "French",
+      Self::German => "German",
+      Self::Greek => "Greek",
+      Self::Hebrew => "Hebrew",
+      Self::Hindi => "Hindi",
+      Self::Hungarian => "Hungarian",
+      Self::Indonesian => "Indonesian",
+      Self::Inuktitut => "Inuktitut",
+      Self::Irish => "Irish",
+      Self::Italian => "Italian",
+      Self::Japanese => "Japanese",
+      Self::Kazakh => "Kazakh",
+      Self::Korean => "Korean",
+      Self::Latin => "Latin",
+      Self::Latvian => "Latvian",
+      Self::Lithuanian => "Lithuanian",
+      Self::Macedonian => "Macedonian",
+      Self::Malay => "Malay",
+      Self::Nepali => "Nepali",
+      Self::Norwegian => "Norwegian",
+      Self::Persian => "Persian",
+      Self::Polish => "Polish",
+      Self::PortugueseBrazil => "Portuguese (Brazil)",
+      Self::PortuguesePortugal => "Portuguese (Portugal)",
+      Self::Romanian => "Romanian",
+      Self::Russian => "Russian",
+      Self::ScottishGaelic => "Scottish Gaelic",
+      Self::Serbian => "Serbian",
+      Self::Slovak => "Slovak",
+      Self::Slovene => "Slovene",
+      Self::Spanish => "Spanish",
+      Self::Swedish => "Swedish",
+      Self::Tagalog => "Tagalog",
+      Self::Thai => "Thai",
+      Self::Turkish => "Turkish",
+      Self::Ukrainian => "Ukrainian",
+      Self::Urdu => "Urdu",
+      Self::Vietnamese => "Vietnamese",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  /// BCP-47 tag of the language.
+  ///
+  /// This is usually the same as the VNDB code, but not always:
+  /// VNDB uses `ta` for Tagalog, which is Tamil in BCP-47.
+  pub fn bcp47(&self) -> &str {
+    match self {
+      Self::Arabic => "ar",
+      Self::Basque => "eu",
+      Self::Belarusian => "be",
+      Self::Bulgarian => "bg",
+      Self::Catalan => "ca",
+      Self::Cherokee => "chr",
+      Self::Chinese => "zh",
+      Self::ChineseSimplified => "zh-Hans",
+      Self::ChineseTraditional => "zh-Hant",
+      Self::Croatian => "hr",
+      Self::Czech => "cs",
+      Self::Danish => "da",
+      Self::Dutch => "nl",
+      Self::English => "en",
+      Self::Esperanto => "eo",
+      Self::Finnish => "fi",
+      Self::French => "fr",
+      Self::German => "de",
+      Self::Greek => "el",
+      Self::Hebrew => "he",
+      Self::Hindi => "hi",
+      Self::Hungarian => "hu",
+      Self::Indonesian => "id",
+      Self::Inuktitut => "iu",
+      Self::Irish => "ga",
+      Self::Italian => "it",
+      Self::Japanese => "ja",
+      Self::Kazakh => "kk",
+      Self::Korean => "ko",
+      Self::Latin => "la",
+      Self::Latvian => "lv",
+      Self::Lithuanian => "lt",
+      Self::Macedonian => "mk",
+      Self::Malay => "ms",
+      Self::Nepali => "ne",
+      Self::Norwegian => "no",
+      Self::Persian => "fa",
+      Self::Polish => "pl",
+      Self::PortugueseBrazil => "pt-BR",
+      Self::PortuguesePortugal => "pt-PT",
+      Self::Romanian => "ro",
+      Self::Russian => "ru",
+      Self::ScottishGaelic => "gd",
+      Self::Serbian => "sr",
+      Self::Slovak => "sk",
+      Self::Slovene => "sl",
+      Self::Spanish => "es",
+      Self::Swedish => "sv",
+      Self::Tagalog => "tl",
+      Self::Thai => "th",
+      Self::Turkish => "tr",
+      Self::Ukrainian => "uk",
+      Self::Urdu => "ur",
+      Self::Vietnamese => "vi",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  /// Primary subtag of the BCP-47 tag, without any script or region.
+  /// Both [`Language::PortugueseBrazil`] and [`Language::PortuguesePortugal`] give `pt`.
+  pub fn base(&self) -> &str {
+    let tag = self.bcp47();
+    tag
+      .split_once('-')
+      .map_or(tag, |(base, _)| base)
+  }
+
+  /// Whether both languages share the same [base](Language::base).
+  pub fn same_base(&self, other: &Self) -> bool {
+    self
+      .base()
+      .eq_ignore_ascii_case(other.base())
+  }
+
+  /// Finds the VNDB language matching a BCP-47 tag, such as `en-US` or `zh-TW`.
+  ///
+  /// Portuguese without a region is assumed to be Brazilian, and Chinese is only
+  /// split by script when the tag has a script or a region that implies one.
+  /// Returns `None` if VNDB has no matching language.
+  pub fn from_bcp47(tag: &str) -> Option<Self> {
+    let tag = tag.replace('_', "-").to_ascii_lowercase();
+    let mut subtags = tag.split('-');
+    let base = subtags.next()?;
+    let rest: Vec<_> = subtags.collect();
+    let has = |subtags: &[&str]| {
+      rest
+        .iter()
+        .any(|subtag| subtags.contains(subtag))
+    };
+
+    let language = match base {
+      "zh" if has(&["hant"]) || (!has(&["hans"]) && has(&["tw", "hk", "mo"])) => {
+        Self::ChineseTraditional
+      }
+      "zh" if has(&["hans", "cn", "sg", "my"]) => Self::ChineseSimplified,
+      "zh" => Self::Chinese,
+      "pt" if rest.is_empty() || has(&["br"]) => Self::PortugueseBrazil,
+      "pt" => Self::PortuguesePortugal,
+      "chr" => Self::Cherokee,
+      "tl" | "fil" => Self::Tagalog,
+      "nb" | "nn" => Self::Norwegian,
+      // These are VNDB codes, but not BCP-47 ones for the same language.
+      "ck" | "ta" => return None,
+      _ => base.parse().ok()?,
+    };
+
+    (!language.is_unknown()).then_some(language)
+  }
+}
+
+impl_str_serde!(Language);
+impl_filter_value!(Language);
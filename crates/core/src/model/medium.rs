@@ -0,0 +1,85 @@
+use crate::{impl_filter_value, impl_str_serde};
+use strum::{Display, EnumIs, EnumString};
+
+/// Medium a release is distributed on.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum Medium {
+  #[strum(serialize = "blr")]
+  BluRay,
+
+  #[strum(serialize = "mrt")]
+  Cartridge,
+
+  #[strum(serialize = "cas")]
+  CassetteTape,
+
+  #[strum(serialize = "cd")]
+  Cd,
+
+  #[strum(serialize = "dc")]
+  DownloadCard,
+
+  #[strum(serialize = "dvd")]
+  Dvd,
+
+  #[strum(serialize = "flp")]
+  Floppy,
+
+  #[strum(serialize = "gdr")]
+  GdRom,
+
+  #[strum(serialize = "in")]
+  InternetDownload,
+
+  #[strum(serialize = "mem")]
+  MemoryCard,
+
+  #[strum(serialize = "nod")]
+  NintendoOpticalDisc,
+
+  #[strum(serialize = "otc")]
+  Other,
+
+  #[strum(serialize = "umd")]
+  Umd,
+
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl Medium {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::BluRay => "Blu-ray disc",
+      Self::Cartridge => "Cartridge",
+      Self::CassetteTape => "Cassette tape",
+      Self::Cd => "CD",
+      Self::DownloadCard => "Download card",
+      Self::Dvd => "DVD",
+      Self::Floppy => "Floppy",
+      Self::GdRom => "GD-ROM",
+      Self::InternetDownload => "Internet download",
+      Self::MemoryCard => "Memory card",
+      Self::NintendoOpticalDisc => "Nintendo Optical Disc",
+      Self::Other => "Other",
+      Self::Umd => "UMD",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  /// Whether the release can come with more than one of this medium, e.g. a set of CDs.
+  /// VNDB always sends a quantity of zero for internet downloads and other media.
+  pub fn has_quantity(&self) -> bool {
+    !matches!(self, Self::InternetDownload | Self::Other)
+  }
+
+  /// Whether the medium has no physical counterpart.
+  pub fn is_digital(&self) -> bool {
+    matches!(self, Self::InternetDownload)
+  }
+}
+
+impl_str_serde!(Medium);
+impl_filter_value!(Medium);
@@ -1,47 +1,72 @@
 pub mod auth_info;
 pub mod character;
+pub mod date;
+pub mod extlink;
+pub mod language;
+pub mod medium;
+pub mod platform;
 pub mod producer;
 pub mod release;
+pub mod rlist;
 pub mod schema;
+pub mod spoiler;
 pub mod staff;
 pub mod stats;
 pub mod tag;
 pub mod r#trait;
+pub mod ulist;
 pub mod user;
 pub mod visual_novel;
 
 pub mod prelude {
   pub use super::auth_info::{AuthInfo, TokenPermission};
   pub use super::character::{
-    Character, CharacterBirthday, CharacterField, CharacterId, CharacterImage, CharacterSex,
-    CharacterSexValue, CharacterTrait, CharacterVisualNovel, SortCharacterBy,
+    Character, CharacterBirthday, CharacterField, CharacterFilter, CharacterId, CharacterImage,
+    CharacterRole, CharacterSex, CharacterSexValue, CharacterTrait, CharacterVisualNovel,
+    SortCharacterBy,
+  };
+  pub use super::date::ReleaseDate;
+  pub use super::extlink::{ExternalLink, ExtlinkId, ExtlinkQuery, ExtlinkSite};
+  pub use super::language::Language;
+  pub use super::medium::Medium;
+  pub use super::platform::{Platform, PlatformFamily};
+  pub use super::producer::{
+    Producer, ProducerField, ProducerFilter, ProducerId, ProducerType, SortProducerBy,
   };
-  pub use super::producer::{Producer, ProducerField, ProducerId, ProducerType, SortProducerBy};
   pub use super::release::{
-    ExternalLink, Release, ReleaseField, ReleaseId, ReleaseImage, ReleaseImageType,
+    Release, ReleaseField, ReleaseFilter, ReleaseId, ReleaseImage, ReleaseImageType,
     ReleaseLanguage, ReleaseMedia, ReleaseProducer, ReleaseResolution, ReleaseType,
     ReleaseVisualNovel, ReleaseVoiced, SortReleaseBy,
   };
-  pub use super::schema::{Language, Schema};
-  pub use super::staff::{SortStaffBy, Staff, StaffAlias, StaffField, StaffGender, StaffId};
+  pub use super::rlist::RlistStatus;
+  pub use super::schema::{Schema, SchemaEnum, SchemaEnumEntry, SchemaExtlink, SchemaFields};
+  pub use super::spoiler::SpoilerLevel;
+  pub use super::staff::{
+    SortStaffBy, Staff, StaffAlias, StaffField, StaffFilter, StaffGender, StaffId, StaffRole,
+  };
   pub use super::stats::Stats;
-  pub use super::tag::{SortTagBy, Tag, TagCategory, TagField, TagId};
-  pub use super::r#trait::{SortTraitBy, Trait, TraitField, TraitId};
+  pub use super::tag::{SortTagBy, Tag, TagCategory, TagField, TagFilter, TagId};
+  pub use super::r#trait::{SortTraitBy, Trait, TraitField, TraitFilter, TraitId};
+  pub use super::ulist::{
+    SortUlistBy, UlistBuiltinLabel, UlistEntry, UlistEntryLabel, UlistField, UlistLabel,
+    UlistLabelField, UlistLabels, UlistRelease, UlistSettableLabel, UlistUpdate, Vote,
+  };
   pub use super::user::{User, UserField, UserId, UserUrlQuery, Users};
   pub use super::visual_novel::{
-    SortVisualNovelBy, VisualNovel, VisualNovelDevStatus, VisualNovelDeveloper, VisualNovelEdition,
-    VisualNovelField, VisualNovelId, VisualNovelImage, VisualNovelLength, VisualNovelRelation,
-    VisualNovelScreenShot, VisualNovelStaff, VisualNovelTag, VisualNovelTitle,
-    VisualNovelVoiceActor,
+    RelationType, SortVisualNovelBy, VisualNovel, VisualNovelDevStatus, VisualNovelDeveloper,
+    VisualNovelEdition, VisualNovelField, VisualNovelFilter, VisualNovelId, VisualNovelImage,
+    VisualNovelLength, VisualNovelRelation, VisualNovelScreenShot, VisualNovelStaff,
+    VisualNovelTag, VisualNovelTitle, VisualNovelVoiceActor,
   };
-  pub use super::{Response, VndbId};
+  pub use super::{Batch, Response, VndbId};
 }
 
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::hash::Hash;
 use std::str::FromStr;
 use strum::EnumIs;
 use url::Url;
@@ -66,6 +91,45 @@ impl<T> IntoIterator for Response<T> {
   }
 }
 
+/// Results of a lookup by many ids, which are sent in concurrent chunks.
+#[remain::sorted]
+#[derive(Clone, Debug, Serialize)]
+pub struct Batch<Id, T> {
+  pub found: HashMap<Id, T>,
+  pub missing: Vec<Id>,
+}
+
+impl<Id, T> Batch<Id, T>
+where
+  Id: Eq + Hash,
+{
+  pub(crate) fn new(ids: Vec<Id>, found: HashMap<Id, T>) -> Self {
+    let missing = ids
+      .into_iter()
+      .filter(|id| !found.contains_key(id))
+      .collect();
+
+    Self { found, missing }
+  }
+
+  pub fn get(&self, id: &Id) -> Option<&T> {
+    self.found.get(id)
+  }
+
+  pub fn is_complete(&self) -> bool {
+    self.missing.is_empty()
+  }
+}
+
+impl<Id, T> IntoIterator for Batch<Id, T> {
+  type Item = (Id, T);
+  type IntoIter = std::collections::hash_map::IntoIter<Id, T>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.found.into_iter()
+  }
+}
+
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize, EnumIs)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -148,6 +212,14 @@ impl fmt::Display for VndbId {
   }
 }
 
+/// Numeric enums are sent by the API as numbers, but serialized by name.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum NumericOrName {
+  Code(u64),
+  Name(String),
+}
+
 pub trait QueryField: fmt::Display + sealed::Sealed {}
 
 pub trait SortQueryBy: fmt::Display + sealed::Sealed {}
@@ -162,6 +234,8 @@ mod sealed {
   impl Sealed for super::staff::StaffField {}
   impl Sealed for super::tag::TagField {}
   impl Sealed for super::r#trait::TraitField {}
+  impl Sealed for super::ulist::UlistField {}
+  impl Sealed for super::ulist::UlistLabelField {}
   impl Sealed for super::user::UserField {}
   impl Sealed for super::visual_novel::VisualNovelField {}
 
@@ -172,5 +246,6 @@ mod sealed {
   impl Sealed for super::staff::SortStaffBy {}
   impl Sealed for super::tag::SortTagBy {}
   impl Sealed for super::r#trait::SortTraitBy {}
+  impl Sealed for super::ulist::SortUlistBy {}
   impl Sealed for super::visual_novel::SortVisualNovelBy {}
 }
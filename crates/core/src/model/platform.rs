@@ -0,0 +1,280 @@
+use crate::{impl_filter_value, impl_str_serde};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIs, EnumString};
+
+/// Platforms a visual novel or release is available on.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum Platform {
+  #[strum(serialize = "and")]
+  Android,
+
+  #[strum(serialize = "bdp")]
+  BluRayPlayer,
+
+  #[strum(serialize = "dos")]
+  Dos,
+
+  #[strum(serialize = "drc")]
+  Dreamcast,
+
+  #[strum(serialize = "dvd")]
+  DvdPlayer,
+
+  #[strum(serialize = "nes")]
+  Famicom,
+
+  #[strum(serialize = "fm7")]
+  Fm7,
+
+  #[strum(serialize = "fm8")]
+  Fm8,
+
+  #[strum(serialize = "fmt")]
+  FmTowns,
+
+  #[strum(serialize = "gba")]
+  GameBoyAdvance,
+
+  #[strum(serialize = "gbc")]
+  GameBoyColor,
+
+  #[strum(serialize = "ios")]
+  Ios,
+
+  #[strum(serialize = "lin")]
+  Linux,
+
+  #[strum(serialize = "mac")]
+  MacOs,
+
+  #[strum(serialize = "mob")]
+  Mobile,
+
+  #[strum(serialize = "msx")]
+  Msx,
+
+  #[strum(serialize = "n3d")]
+  Nintendo3ds,
+
+  #[strum(serialize = "nds")]
+  NintendoDs,
+
+  #[strum(serialize = "swi")]
+  NintendoSwitch,
+
+  #[strum(serialize = "sw2")]
+  NintendoSwitch2,
+
+  #[strum(serialize = "wii")]
+  NintendoWii,
+
+  #[strum(serialize = "wiu")]
+  NintendoWiiU,
+
+  #[strum(serialize = "oth")]
+  Other,
+
+  #[strum(serialize = "p88")]
+  Pc88,
+
+  #[strum(serialize = "p98")]
+  Pc98,
+
+  #[strum(serialize = "pce")]
+  PcEngine,
+
+  #[strum(serialize = "pcf")]
+  PcFx,
+
+  #[strum(serialize = "ps1")]
+  PlayStation1,
+
+  #[strum(serialize = "ps2")]
+  PlayStation2,
+
+  #[strum(serialize = "ps3")]
+  PlayStation3,
+
+  #[strum(serialize = "ps4")]
+  PlayStation4,
+
+  #[strum(serialize = "ps5")]
+  PlayStation5,
+
+  #[strum(serialize = "psp")]
+  PlayStationPortable,
+
+  #[strum(serialize = "psv")]
+  PlayStationVita,
+
+  #[strum(serialize = "scd")]
+  SegaMegaCd,
+
+  #[strum(serialize = "smd")]
+  SegaMegaDrive,
+
+  #[strum(serialize = "sat")]
+  SegaSaturn,
+
+  #[strum(serialize = "x1s")]
+  SharpX1,
+
+  #[strum(serialize = "x68")]
+  SharpX68000,
+
+  #[strum(serialize = "sfc")]
+  SuperFamicom,
+
+  #[strum(serialize = "tdo")]
+  ThreeDo,
+
+  #[strum(default)]
+  Unknown(String),
+
+  #[strum(serialize = "vnds")]
+  Vnds,
+
+  #[strum(serialize = "web")]
+  Website,
+
+  #[strum(serialize = "win")]
+  Windows,
+
+  #[strum(serialize = "xb1")]
+  Xbox,
+
+  #[strum(serialize = "xb3")]
+  Xbox360,
+
+  #[strum(serialize = "xbo")]
+  XboxOne,
+
+  #[strum(serialize = "xxs")]
+  XboxSeries,
+}
+
+impl Platform {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Android => "Android",
+      Self::BluRayPlayer => "Blu-ray Player",
+      Self::Dos => "DOS",
+      Self::Dreamcast => "Dreamcast",
+      Self::DvdPlayer => "DVD Player",
+      Self::Famicom => "Famicom",
+      Self::Fm7 => "FM-7",
+      Self::Fm8 => "FM-8",
+      Self::FmTowns => "FM Towns",
+      Self::GameBoyAdvance => "Game Boy Advance",
+      Self::GameBoyColor => "Game Boy Color",
+      Self::Ios => "Apple iProduct",
+      Self::Linux => "Linux",
+      Self::MacOs => "Mac OS",
+      Self::Mobile => "Other (mobile)",
+      Self::Msx => "MSX",
+      Self::Nintendo3ds => "Nintendo 3DS",
+      Self::NintendoDs => "Nintendo DS",
+      Self::NintendoSwitch => "Nintendo Switch",
+      Self::NintendoSwitch2 => "Nintendo Switch 2",
+      Self::NintendoWii => "Nintendo Wii",
+      Self::NintendoWiiU => "Nintendo Wii U",
+      Self::Other => "Other",
+      Self::Pc88 => "PC-88",
+      Self::Pc98 => "PC-98",
+      Self::PcEngine => "PC Engine",
+      Self::PcFx => "PC-FX",
+      Self::PlayStation1 => "PlayStation 1",
+      Self::PlayStation2 => "PlayStation 2",
+      Self::PlayStation3 => "PlayStation 3",
+      Self::PlayStation4 => "PlayStation 4",
+      Self::PlayStation5 => "PlayStation 5",
+      Self::PlayStationPortable => "PlayStation Portable",
+      Self::PlayStationVita => "PlayStation Vita",
+      Self::SegaMegaCd => "Sega Mega-CD",
+      Self::SegaMegaDrive => "Sega Mega Drive",
+      Self::SegaSaturn => "Sega Saturn",
+      Self::SharpX1 => "Sharp X1",
+      Self::SharpX68000 => "Sharp X68000",
+      Self::SuperFamicom => "Super Famicom",
+      Self::ThreeDo => "3DO",
+      Self::Vnds => "VNDS",
+      Self::Website => "Website",
+      Self::Windows => "Windows",
+      Self::Xbox => "Xbox",
+      Self::Xbox360 => "Xbox 360",
+      Self::XboxOne => "Xbox One",
+      Self::XboxSeries => "Xbox X/S",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  pub fn family(&self) -> PlatformFamily {
+    match self {
+      Self::Dreamcast
+      | Self::Famicom
+      | Self::GameBoyAdvance
+      | Self::GameBoyColor
+      | Self::Nintendo3ds
+      | Self::NintendoDs
+      | Self::NintendoSwitch
+      | Self::NintendoSwitch2
+      | Self::NintendoWii
+      | Self::NintendoWiiU
+      | Self::PcEngine
+      | Self::PcFx
+      | Self::PlayStation1
+      | Self::PlayStation2
+      | Self::PlayStation3
+      | Self::PlayStation4
+      | Self::PlayStation5
+      | Self::PlayStationPortable
+      | Self::PlayStationVita
+      | Self::SegaMegaCd
+      | Self::SegaMegaDrive
+      | Self::SegaSaturn
+      | Self::SuperFamicom
+      | Self::ThreeDo
+      | Self::Xbox
+      | Self::Xbox360
+      | Self::XboxOne
+      | Self::XboxSeries => PlatformFamily::Console,
+      Self::Dos
+      | Self::Fm7
+      | Self::Fm8
+      | Self::FmTowns
+      | Self::Linux
+      | Self::MacOs
+      | Self::Msx
+      | Self::Pc88
+      | Self::Pc98
+      | Self::SharpX1
+      | Self::SharpX68000
+      | Self::Windows => PlatformFamily::Desktop,
+      Self::Android | Self::Ios | Self::Mobile => PlatformFamily::Mobile,
+      Self::BluRayPlayer
+      | Self::DvdPlayer
+      | Self::Other
+      | Self::Vnds
+      | Self::Website
+      | Self::Unknown(_) => PlatformFamily::Other,
+    }
+  }
+}
+
+impl_str_serde!(Platform);
+impl_filter_value!(Platform);
+
+/// Broad grouping of platforms.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum PlatformFamily {
+  Console,
+  Desktop,
+  Mobile,
+  /// Websites, media players and anything else.
+  Other,
+}
@@ -1,7 +1,8 @@
-use super::schema::Language;
+use super::extlink::{ExternalLink, ExtlinkQuery};
+use super::language::Language;
 use super::{QueryField, SortQueryBy};
-use crate::model::release::ExternalLink;
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality};
+use crate::{impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
@@ -17,6 +18,7 @@ pub struct Producer {
   pub description: Option<String>,
   pub extlinks: Option<Vec<ExternalLink>>,
   pub id: ProducerId,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub lang: Option<Language>,
   pub name: Option<String>,
   pub original: Option<String>,
@@ -53,7 +55,7 @@ impl_id_newtype!(Producer, ProducerId, ID_REGEX);
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(
-  Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
 )]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum ProducerType {
@@ -68,6 +70,10 @@ pub enum ProducerType {
   #[serde(rename = "in")]
   #[strum(serialize = "in")]
   Individual,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
 
 #[non_exhaustive]
@@ -143,3 +149,30 @@ pub enum SortProducerBy {
 }
 
 impl SortQueryBy for SortProducerBy {}
+
+/// See: <https://api.vndb.org/kana#producer-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProducerFilter {
+  And(Vec<Self>),
+  Extlink(Equality, ExtlinkQuery),
+  Id(Comparison, ProducerId),
+  Language(Equality, Language),
+  Or(Vec<Self>),
+  Search(Equality, String),
+  Type(Equality, ProducerType),
+}
+
+impl_filter!(ProducerFilter {
+  values: {
+    Extlink => "extlink",
+    Id => "id",
+    Language => "lang",
+    Search => "search",
+    Type => "type",
+  },
+  flags: {},
+});
+
+impl_filter_value!(ProducerId, ProducerType);
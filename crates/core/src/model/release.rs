@@ -1,14 +1,26 @@
-use super::producer::Producer;
-use super::schema::Language;
-use super::visual_novel::{VisualNovel, VisualNovelId, VisualNovelImage};
+use super::date::ReleaseDate;
+use super::extlink::ExtlinkQuery;
+use super::language::Language;
+use super::medium::Medium;
+use super::platform::Platform;
+use super::producer::{Producer, ProducerField, ProducerFilter};
+use super::visual_novel::{
+  VisualNovel, VisualNovelField, VisualNovelFilter, VisualNovelId, VisualNovelImage,
+};
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality};
+use crate::{
+  impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set, impl_nested_fields,
+  impl_numeric_enum,
+};
 use regex::Regex;
-use serde::{Deserialize, Deserializer, Serialize};
-use serde_json::Value as JsonValue;
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
 use strum::{Display, EnumIs, EnumString, VariantArray};
 
+// Kept here so paths from before `ExternalLink` got its own module still work.
+pub use super::extlink::ExternalLink;
+
 static ID_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^r\d+$").unwrap());
 
 #[remain::sorted]
@@ -30,11 +42,15 @@ pub struct Release {
   pub notes: Option<String>,
   pub official: Option<bool>,
   pub patch: Option<bool>,
-  pub platforms: Option<Vec<String>>,
-  pub released: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<Vec<String>>))]
+  pub platforms: Option<Vec<Platform>>,
+  pub producers: Option<Vec<ReleaseProducer>>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub released: Option<ReleaseDate>,
   pub resolution: Option<ReleaseResolution>,
   pub title: Option<String>,
   pub uncensored: Option<bool>,
+  pub vns: Option<Vec<ReleaseVisualNovel>>,
   pub voiced: Option<ReleaseVoiced>,
 }
 
@@ -71,6 +87,7 @@ impl_id_newtype!(Release, ReleaseId, ID_REGEX);
 pub struct ReleaseImage {
   #[serde(flatten)]
   pub image: VisualNovelImage,
+  #[cfg_attr(feature = "specta", specta(type = Option<Vec<String>>))]
   pub languages: Option<Vec<Language>>,
   pub photo: Option<bool>,
   pub r#type: Option<ReleaseImageType>,
@@ -85,8 +102,12 @@ impl From<ReleaseImage> for VisualNovelImage {
 
 #[non_exhaustive]
 #[remain::sorted]
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs)]
+#[derive(
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
 pub enum ReleaseImageType {
   Dig,
   PkgBack,
@@ -94,12 +115,16 @@ pub enum ReleaseImageType {
   PkgFront,
   PkgMed,
   PkgSide,
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
 
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ReleaseLanguage {
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub lang: Option<Language>,
   pub latin: Option<String>,
   pub main: Option<bool>,
@@ -111,10 +136,19 @@ pub struct ReleaseLanguage {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ReleaseMedia {
-  pub medium: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub medium: Option<Medium>,
   pub qty: Option<u32>,
 }
 
+impl ReleaseMedia {
+  /// Number of items of the medium, or `None` if it does not apply, as with internet downloads.
+  pub fn quantity(&self) -> Option<u32> {
+    let medium = self.medium.as_ref()?;
+    self.qty.filter(|_| medium.has_quantity())
+  }
+}
+
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -185,33 +219,16 @@ pub enum ReleaseVoiced {
   NotVoiced,
   OnlyEroScenes,
   PartiallyVoiced,
+  #[serde(untagged)]
+  Unknown(u64),
 }
 
-impl<'de> Deserialize<'de> for ReleaseVoiced {
-  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-  where
-    D: Deserializer<'de>,
-  {
-    use serde::de::Error;
-    match u8::deserialize(deserializer)? {
-      1 => Ok(ReleaseVoiced::NotVoiced),
-      2 => Ok(ReleaseVoiced::OnlyEroScenes),
-      3 => Ok(ReleaseVoiced::PartiallyVoiced),
-      4 => Ok(ReleaseVoiced::FullyVoiced),
-      _ => Err(D::Error::custom("Invalid release voiced value")),
-    }
-  }
-}
-
-#[remain::sorted]
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[cfg_attr(feature = "specta", derive(specta::Type))]
-pub struct ExternalLink {
-  pub id: Option<JsonValue>,
-  pub label: Option<String>,
-  pub name: Option<String>,
-  pub url: Option<String>,
-}
+impl_numeric_enum!(ReleaseVoiced {
+  1 => NotVoiced,
+  2 => OnlyEroScenes,
+  3 => PartiallyVoiced,
+  4 => FullyVoiced,
+}, Unknown);
 
 #[non_exhaustive]
 #[remain::sorted]
@@ -379,6 +396,11 @@ impl QueryField for ReleaseField {}
 
 impl_into_field_set!(ReleaseField);
 
+impl_nested_fields!(ReleaseField {
+  producers => "producers": ProducerField,
+  vns => "vns": VisualNovelField,
+});
+
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString)]
@@ -402,3 +424,62 @@ pub enum SortReleaseBy {
 }
 
 impl SortQueryBy for SortReleaseBy {}
+
+/// See: <https://api.vndb.org/kana#release-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReleaseFilter {
+  And(Vec<Self>),
+  Engine(Equality, String),
+  Extlink(Equality, ExtlinkQuery),
+  Freeware(bool),
+  HasEro(bool),
+  Id(Comparison, ReleaseId),
+  Language(Equality, Language),
+  Medium(Equality, Medium),
+  MinAge(Comparison, u8),
+  Official(bool),
+  Or(Vec<Self>),
+  Patch(bool),
+  Platform(Equality, Platform),
+  Producer(Equality, Box<ProducerFilter>),
+  Released(Comparison, ReleaseDate),
+  /// Width and height.
+  Resolution(Comparison, (u32, u32)),
+  ResolutionAspect(Comparison, (u32, u32)),
+  Search(Equality, String),
+  Type(Equality, ReleaseType),
+  Uncensored(bool),
+  VisualNovel(Equality, Box<VisualNovelFilter>),
+  Voiced(Equality, ReleaseVoiced),
+}
+
+impl_filter!(ReleaseFilter {
+  values: {
+    Engine => "engine",
+    Extlink => "extlink",
+    Id => "id",
+    Language => "lang",
+    Medium => "medium",
+    MinAge => "minage",
+    Platform => "platform",
+    Producer => "producer",
+    Released => "released",
+    Resolution => "resolution",
+    ResolutionAspect => "resolution_aspect",
+    Search => "search",
+    Type => "rtype",
+    VisualNovel => "vn",
+    Voiced => "voiced",
+  },
+  flags: {
+    Freeware => "freeware",
+    HasEro => "has_ero",
+    Official => "official",
+    Patch => "patch",
+    Uncensored => "uncensored",
+  },
+});
+
+impl_filter_value!(ReleaseId, ReleaseType);
@@ -0,0 +1,28 @@
+use crate::impl_numeric_enum;
+use serde::Serialize;
+use strum::{Display, EnumIs};
+
+/// Status of a release in the user's release list.
+///
+/// See: <https://api.vndb.org/kana#patch-rlistid>
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Serialize, PartialEq, Eq, Hash, Display, EnumIs)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum RlistStatus {
+  Unknown,
+  Pending,
+  Obtained,
+  OnLoan,
+  Deleted,
+  /// Any other code, as [`Unknown`](Self::Unknown) is status 0.
+  #[serde(untagged)]
+  Other(u64),
+}
+
+impl_numeric_enum!(RlistStatus {
+  0 => Unknown,
+  1 => Pending,
+  2 => Obtained,
+  3 => OnLoan,
+  4 => Deleted,
+}, Other);
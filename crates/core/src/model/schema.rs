@@ -1,75 +1,166 @@
-use serde::{Deserialize, Deserializer, Serialize};
-use serde_json::Value as JsonValue;
-use std::result::Result as StdResult;
-use strum::{Display, EnumString};
+use super::QueryField;
+use super::extlink::{ExtlinkId, ExtlinkSite};
+use super::medium::Medium;
+use super::platform::Platform;
+use super::staff::StaffRole;
+use crate::http::{Endpoint, FieldSet};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+// Kept here so paths from before `Language` got its own module still work.
+pub use super::language::Language;
+
+/// Machine-readable description of the API, useful to validate queries offline.
+///
+/// See: <https://api.vndb.org/kana#get-schema>
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct Schema {
-  pub api_fields: JsonValue,
+  /// Fields of each endpoint, keyed by path, e.g. `/vn`.
+  pub api_fields: HashMap<String, SchemaFields>,
   pub enums: SchemaEnum,
-  pub extlinks: JsonValue,
+  /// Sites each endpoint can link to, keyed by path, e.g. `/release`.
+  pub extlinks: HashMap<String, Vec<SchemaExtlink>>,
+}
+
+impl Schema {
+  /// Fields of the endpoint, or `None` if the schema doesn't describe it.
+  pub fn fields(&self, endpoint: Endpoint) -> Option<&SchemaFields> {
+    self.api_fields.get(&path(endpoint))
+  }
+
+  /// Whether a field, such as `titles.lang`, can be requested from the endpoint.
+  ///
+  /// Only fields without subfields can be requested,
+  /// so `image.url` is valid but `image` on its own is not.
+  pub fn is_valid_field(&self, endpoint: Endpoint, field: &str) -> bool {
+    let Some(mut fields) = self.fields(endpoint) else {
+      return false;
+    };
+
+    let mut names = field.split('.');
+    while let Some(name) = names.next() {
+      match self.lookup(fields, name) {
+        Some(Some(subfields)) => fields = subfields,
+        Some(None) => return names.next().is_none(),
+        None => return false,
+      }
+    }
+
+    false
+  }
+
+  /// Fields of the set that can't be requested from the endpoint, in alphabetical order.
+  pub fn invalid_fields<'a, T>(&self, endpoint: Endpoint, fields: &'a FieldSet<T>) -> Vec<&'a str>
+  where
+    T: QueryField,
+  {
+    fields
+      .iter_raw()
+      .filter(|field| !self.is_valid_field(endpoint, field))
+      .sorted_unstable()
+      .collect()
+  }
+
+  /// Sites the endpoint can link to.
+  pub fn extlink_sites(&self, endpoint: Endpoint) -> &[SchemaExtlink] {
+    self
+      .extlinks
+      .get(&path(endpoint))
+      .map(Vec::as_slice)
+      .unwrap_or_default()
+  }
+
+  /// Definition of a site the endpoint can link to.
+  pub fn extlink(&self, endpoint: Endpoint, site: &ExtlinkSite) -> Option<&SchemaExtlink> {
+    self
+      .extlink_sites(endpoint)
+      .iter()
+      .find(|extlink| &extlink.name == site)
+  }
+
+  /// Looks a field up, falling back to the endpoint the fields inherit from.
+  fn lookup<'a>(
+    &'a self,
+    fields: &'a SchemaFields,
+    name: &str,
+  ) -> Option<&'a Option<SchemaFields>> {
+    fields.fields.get(name).or_else(|| {
+      let inherited = self
+        .api_fields
+        .get(fields.inherit.as_deref()?)?;
+      self.lookup(inherited, name)
+    })
+  }
+}
+
+/// Fields of an endpoint or of a nested object.
+/// Fields without subfields are `None`.
+#[remain::sorted]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SchemaFields {
+  #[serde(flatten)]
+  pub fields: HashMap<String, Option<SchemaFields>>,
+  /// Path of the endpoint whose fields are also available here, e.g. `/vn`.
+  #[serde(rename = "_inherit", skip_serializing_if = "Option::is_none")]
+  pub inherit: Option<String>,
 }
 
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct SchemaEnum {
-  pub language: JsonValue,
-  pub medium: JsonValue,
-  pub platform: JsonValue,
-  pub staff_role: JsonValue,
+  #[cfg_attr(feature = "specta", specta(type = Vec<SchemaEnumEntry<String>>))]
+  pub language: Vec<SchemaEnumEntry<Language>>,
+  #[cfg_attr(feature = "specta", specta(type = Vec<SchemaEnumEntry<String>>))]
+  pub medium: Vec<SchemaEnumEntry<Medium>>,
+  #[cfg_attr(feature = "specta", specta(type = Vec<SchemaEnumEntry<String>>))]
+  pub platform: Vec<SchemaEnumEntry<Platform>>,
+  #[cfg_attr(feature = "specta", specta(type = Vec<SchemaEnumEntry<String>>))]
+  pub staff_role: Vec<SchemaEnumEntry<StaffRole>>,
 }
 
-#[non_exhaustive]
 #[remain::sorted]
-#[derive(Clone, Debug, Serialize, PartialEq, Eq, Hash, Display, EnumString)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
-pub enum Language {
-  #[serde(rename = "zh")]
-  #[strum(serialize = "zh")]
-  Chinese,
-
-  #[serde(rename = "en")]
-  #[strum(serialize = "en")]
-  English,
-
-  #[serde(rename = "ja")]
-  #[strum(serialize = "ja")]
-  Japanese,
-
-  #[serde(rename = "ko")]
-  #[strum(serialize = "ko")]
-  Korean,
-
-  #[serde(rename = "pt")]
-  #[strum(serialize = "pt")]
-  Portuguese,
+pub struct SchemaEnumEntry<T> {
+  pub id: T,
+  pub label: String,
+  /// Only sent for media, as they can have a quantity.
+  pub plural: Option<String>,
+}
 
-  #[serde(rename = "ru")]
-  #[strum(serialize = "ru")]
-  Russian,
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SchemaExtlink {
+  pub label: String,
+  #[cfg_attr(feature = "specta", specta(type = String))]
+  pub name: ExtlinkSite,
+  /// URL of a page on the site, with a `printf`-style placeholder for the id.
+  pub url_format: String,
+}
 
-  #[serde(rename = "es")]
-  #[strum(serialize = "es")]
-  Spanish,
+impl SchemaExtlink {
+  /// Builds the URL of a page on the site.
+  /// Returns `None` if the format has no placeholder for the id.
+  pub fn url(&self, id: &ExtlinkId) -> Option<String> {
+    let (prefix, rest) = self.url_format.split_once('%')?;
+    let width_len = rest
+      .find(|c: char| !c.is_ascii_digit())
+      .unwrap_or(rest.len());
 
-  Unknown(String),
+    let (width, rest) = rest.split_at(width_len);
+    let suffix = rest.strip_prefix(['d', 's'])?;
+    let width = width.parse().unwrap_or(0);
+    let id = id.to_string();
+    Some(format!("{prefix}{id:0>width$}{suffix}"))
+  }
 }
 
-impl<'de> Deserialize<'de> for Language {
-  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
-    let s = String::deserialize(deserializer)?;
-    Ok(match s.as_str() {
-      "zh" | "zh-Hans" | "zh-Hant" => Language::Chinese,
-      "en" => Language::English,
-      "ja" => Language::Japanese,
-      "ko" => Language::Korean,
-      "pt-br" | "pt-pt" => Language::Portuguese,
-      "ru" => Language::Russian,
-      "es" => Language::Spanish,
-      _ => Language::Unknown(s),
-    })
-  }
+fn path(endpoint: Endpoint) -> String {
+  format!("/{endpoint}")
 }
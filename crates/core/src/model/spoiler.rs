@@ -0,0 +1,39 @@
+use crate::impl_numeric_enum;
+use serde::Serialize;
+use std::cmp::Ordering;
+use strum::{Display, EnumIs};
+
+/// How much of a spoiler a tag, trait or character appearance is.
+///
+/// Levels are ordered from [`SpoilerLevel::None`] to [`SpoilerLevel::Major`].
+/// Levels added by VNDB after this was written sort after every known one,
+/// so they are hidden whenever major spoilers are.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Copy, Clone, Debug, Serialize, PartialEq, Eq, Hash, Display, EnumIs)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum SpoilerLevel {
+  Major,
+  Minor,
+  None,
+  #[serde(untagged)]
+  Unknown(u64),
+}
+
+impl_numeric_enum!(SpoilerLevel {
+  0 => None,
+  1 => Minor,
+  2 => Major,
+}, Unknown);
+
+impl PartialOrd for SpoilerLevel {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for SpoilerLevel {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.code().cmp(&other.code())
+  }
+}
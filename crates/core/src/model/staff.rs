@@ -1,7 +1,8 @@
-use super::release::ExternalLink;
-use super::schema::Language;
+use super::extlink::{ExternalLink, ExtlinkQuery};
+use super::language::Language;
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality};
+use crate::{impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set, impl_str_serde};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
@@ -20,6 +21,7 @@ pub struct Staff {
   pub gender: Option<StaffGender>,
   pub id: StaffId,
   pub ismain: Option<bool>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub lang: Option<Language>,
   pub name: Option<String>,
   pub original: Option<String>,
@@ -65,7 +67,7 @@ pub struct StaffAlias {
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(
-  Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
 )]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum StaffGender {
@@ -76,8 +78,71 @@ pub enum StaffGender {
   #[serde(rename = "m")]
   #[strum(serialize = "m")]
   Male,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
 
+/// Role of a staff member in a visual novel.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum StaffRole {
+  #[strum(serialize = "art")]
+  Artist,
+
+  #[strum(serialize = "chardesign")]
+  CharacterDesign,
+
+  #[strum(serialize = "music")]
+  Composer,
+
+  #[strum(serialize = "director")]
+  Director,
+
+  #[strum(serialize = "editor")]
+  Editor,
+
+  #[strum(serialize = "qa")]
+  QualityAssurance,
+
+  #[strum(serialize = "scenario")]
+  Scenario,
+
+  #[strum(serialize = "staff")]
+  Staff,
+
+  #[strum(serialize = "translator")]
+  Translator,
+
+  #[strum(default)]
+  Unknown(String),
+
+  #[strum(serialize = "songs")]
+  Vocals,
+}
+
+impl StaffRole {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Artist => "Artist",
+      Self::CharacterDesign => "Character design",
+      Self::Composer => "Composer",
+      Self::Director => "Director",
+      Self::Editor => "Editor",
+      Self::QualityAssurance => "Quality assurance",
+      Self::Scenario => "Scenario",
+      Self::Staff => "Staff",
+      Self::Translator => "Translator",
+      Self::Vocals => "Vocals",
+      Self::Unknown(code) => code,
+    }
+  }
+}
+
+impl_str_serde!(StaffRole);
+
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString, VariantArray)]
@@ -171,3 +236,39 @@ pub enum SortStaffBy {
 }
 
 impl SortQueryBy for SortStaffBy {}
+
+/// See: <https://api.vndb.org/kana#staff-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StaffFilter {
+  /// Alias id.
+  Aid(Equality, u32),
+  And(Vec<Self>),
+  Extlink(Equality, ExtlinkQuery),
+  Gender(Equality, StaffGender),
+  Id(Comparison, StaffId),
+  /// Only matches the main name of the staff, not their aliases.
+  IsMain(bool),
+  Language(Equality, Language),
+  Or(Vec<Self>),
+  Role(Equality, StaffRole),
+  Search(Equality, String),
+}
+
+impl_filter!(StaffFilter {
+  values: {
+    Aid => "aid",
+    Extlink => "extlink",
+    Gender => "gender",
+    Id => "id",
+    Language => "lang",
+    Role => "role",
+    Search => "search",
+  },
+  flags: {
+    IsMain => "ismain",
+  },
+});
+
+impl_filter_value!(StaffId, StaffGender, StaffRole);
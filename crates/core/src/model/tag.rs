@@ -1,5 +1,6 @@
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality};
+use crate::{impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
@@ -51,7 +52,7 @@ impl_id_newtype!(Tag, TagId, ID_REGEX);
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(
-  Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
+  Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Display, EnumIs, EnumString,
 )]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum TagCategory {
@@ -66,6 +67,10 @@ pub enum TagCategory {
   #[serde(rename = "tech")]
   #[strum(serialize = "tech")]
   Technical,
+
+  #[serde(untagged)]
+  #[strum(default)]
+  Unknown(String),
 }
 
 #[non_exhaustive]
@@ -133,3 +138,26 @@ pub enum SortTagBy {
 }
 
 impl SortQueryBy for SortTagBy {}
+
+/// See: <https://api.vndb.org/kana#tag-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TagFilter {
+  And(Vec<Self>),
+  Category(Equality, TagCategory),
+  Id(Comparison, TagId),
+  Or(Vec<Self>),
+  Search(Equality, String),
+}
+
+impl_filter!(TagFilter {
+  values: {
+    Category => "category",
+    Id => "id",
+    Search => "search",
+  },
+  flags: {},
+});
+
+impl_filter_value!(TagId, TagCategory);
@@ -1,5 +1,6 @@
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality};
+use crate::{impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
@@ -123,3 +124,24 @@ pub enum SortTraitBy {
 }
 
 impl SortQueryBy for SortTraitBy {}
+
+/// See: <https://api.vndb.org/kana#trait-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TraitFilter {
+  And(Vec<Self>),
+  Id(Comparison, TraitId),
+  Or(Vec<Self>),
+  Search(Equality, String),
+}
+
+impl_filter!(TraitFilter {
+  values: {
+    Id => "id",
+    Search => "search",
+  },
+  flags: {},
+});
+
+impl_filter_value!(TraitId);
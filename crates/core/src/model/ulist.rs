@@ -0,0 +1,596 @@
+use super::date::ReleaseDate;
+use super::release::{Release, ReleaseField};
+use super::rlist::RlistStatus;
+use super::visual_novel::{VisualNovel, VisualNovelField, VisualNovelId};
+use super::{QueryField, SortQueryBy};
+use crate::error::{Error, Result};
+use crate::{impl_into_field_set, impl_nested_fields};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value as JsonValue;
+use std::ops::{Deref, DerefMut};
+use std::result::Result as StdResult;
+use strum::{Display, EnumIs, EnumString, FromRepr, VariantArray};
+
+/// A visual novel in a user's list.
+///
+/// See: <https://api.vndb.org/kana#post-ulist>
+#[remain::sorted]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UlistEntry {
+  /// Unix timestamp of when the visual novel was added to the list.
+  pub added: Option<u64>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub finished: Option<ReleaseDate>,
+  pub id: VisualNovelId,
+  pub labels: Option<Vec<UlistEntryLabel>>,
+  /// Unix timestamp of the last change to the entry.
+  pub lastmod: Option<u64>,
+  pub notes: Option<String>,
+  /// Releases of this visual novel in the user's release list.
+  pub releases: Option<Vec<UlistRelease>>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub started: Option<ReleaseDate>,
+  pub vn: Option<VisualNovel>,
+  pub vote: Option<Vote>,
+  /// Unix timestamp of when the vote was cast.
+  pub voted: Option<u64>,
+}
+
+impl From<UlistEntry> for VisualNovelId {
+  fn from(entry: UlistEntry) -> Self {
+    entry.id
+  }
+}
+
+impl<'de> Deserialize<'de> for UlistEntry {
+  fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    use serde::de::Error;
+
+    // The nested visual novel only has an id if it was requested,
+    // but it is always the same as the id of the entry.
+    let mut value = JsonValue::deserialize(deserializer)?;
+    if let Some(id) = value.get("id").cloned()
+      && let Some(JsonValue::Object(vn)) = value.get_mut("vn")
+    {
+      vn.entry("id").or_insert(id);
+    }
+
+    let entry: RawUlistEntry = serde_json::from_value(value).map_err(D::Error::custom)?;
+    Ok(Self {
+      added: entry.added,
+      finished: entry.finished,
+      id: entry.id,
+      labels: entry.labels,
+      lastmod: entry.lastmod,
+      notes: entry.notes,
+      releases: entry.releases,
+      started: entry.started,
+      vn: entry.vn,
+      vote: entry.vote,
+      voted: entry.voted,
+    })
+  }
+}
+
+#[remain::sorted]
+#[derive(Deserialize)]
+struct RawUlistEntry {
+  added: Option<u64>,
+  finished: Option<ReleaseDate>,
+  id: VisualNovelId,
+  labels: Option<Vec<UlistEntryLabel>>,
+  lastmod: Option<u64>,
+  notes: Option<String>,
+  releases: Option<Vec<UlistRelease>>,
+  started: Option<ReleaseDate>,
+  vn: Option<VisualNovel>,
+  vote: Option<Vote>,
+  voted: Option<u64>,
+}
+
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UlistRelease {
+  pub list_status: Option<RlistStatus>,
+  #[serde(flatten)]
+  pub release: Release,
+}
+
+impl From<UlistRelease> for Release {
+  fn from(release: UlistRelease) -> Self {
+    release.release
+  }
+}
+
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UlistEntryLabel {
+  pub id: u32,
+  pub label: Option<String>,
+}
+
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString, VariantArray)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UlistField {
+  #[serde(rename = "added")]
+  #[strum(serialize = "added")]
+  Added,
+
+  #[serde(rename = "finished")]
+  #[strum(serialize = "finished")]
+  Finished,
+
+  #[serde(rename = "id")]
+  #[strum(serialize = "id")]
+  Id,
+
+  #[serde(rename = "labels.id")]
+  #[strum(serialize = "labels.id")]
+  LabelsId,
+
+  #[serde(rename = "labels.label")]
+  #[strum(serialize = "labels.label")]
+  LabelsLabel,
+
+  #[serde(rename = "lastmod")]
+  #[strum(serialize = "lastmod")]
+  LastMod,
+
+  #[serde(rename = "notes")]
+  #[strum(serialize = "notes")]
+  Notes,
+
+  #[serde(rename = "releases.list_status")]
+  #[strum(serialize = "releases.list_status")]
+  ReleasesListStatus,
+
+  #[serde(rename = "started")]
+  #[strum(serialize = "started")]
+  Started,
+
+  #[serde(rename = "vote")]
+  #[strum(serialize = "vote")]
+  Vote,
+
+  #[serde(rename = "voted")]
+  #[strum(serialize = "voted")]
+  Voted,
+}
+
+impl QueryField for UlistField {}
+
+impl_into_field_set!(UlistField);
+
+impl_nested_fields!(UlistField {
+  releases => "releases": ReleaseField,
+  vn => "vn": VisualNovelField,
+});
+
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum SortUlistBy {
+  #[serde(rename = "added")]
+  #[strum(serialize = "added")]
+  Added,
+
+  #[serde(rename = "finished")]
+  #[strum(serialize = "finished")]
+  Finished,
+
+  #[serde(rename = "id")]
+  #[strum(serialize = "id")]
+  Id,
+
+  #[serde(rename = "lastmod")]
+  #[strum(serialize = "lastmod")]
+  LastMod,
+
+  #[serde(rename = "rating")]
+  #[strum(serialize = "rating")]
+  Rating,
+
+  #[serde(rename = "released")]
+  #[strum(serialize = "released")]
+  Released,
+
+  #[serde(rename = "searchrank")]
+  #[strum(serialize = "searchrank")]
+  SearchRank,
+
+  #[serde(rename = "started")]
+  #[strum(serialize = "started")]
+  Started,
+
+  #[serde(rename = "title")]
+  #[strum(serialize = "title")]
+  Title,
+
+  #[serde(rename = "vote")]
+  #[strum(serialize = "vote")]
+  Vote,
+
+  #[serde(rename = "votecount")]
+  #[strum(serialize = "votecount")]
+  VoteCount,
+
+  #[serde(rename = "voted")]
+  #[strum(serialize = "voted")]
+  Voted,
+}
+
+impl SortQueryBy for SortUlistBy {}
+
+/// Changes to a visual novel in the user's list. Fields that are not set are left untouched.
+///
+/// See: <https://api.vndb.org/kana#patch-ulistid>
+#[remain::sorted]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UlistUpdate {
+  #[serde(skip_serializing_if = "FieldUpdate::is_keep")]
+  finished: FieldUpdate<ReleaseDate>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  labels_set: Vec<u32>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  labels_unset: Vec<u32>,
+  #[serde(skip_serializing_if = "FieldUpdate::is_keep")]
+  notes: FieldUpdate<String>,
+  #[serde(skip_serializing_if = "FieldUpdate::is_keep")]
+  started: FieldUpdate<ReleaseDate>,
+  #[serde(skip_serializing_if = "FieldUpdate::is_keep")]
+  vote: FieldUpdate<Vote>,
+}
+
+impl UlistUpdate {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  #[must_use]
+  pub fn vote(mut self, vote: Vote) -> Self {
+    self.vote = FieldUpdate::Set(vote);
+    self
+  }
+
+  #[must_use]
+  pub fn remove_vote(mut self) -> Self {
+    self.vote = FieldUpdate::Remove;
+    self
+  }
+
+  #[must_use]
+  pub fn notes(mut self, notes: impl Into<String>) -> Self {
+    self.notes = FieldUpdate::Set(notes.into());
+    self
+  }
+
+  #[must_use]
+  pub fn remove_notes(mut self) -> Self {
+    self.notes = FieldUpdate::Remove;
+    self
+  }
+
+  /// # Errors
+  ///
+  /// Fails unless the date has a year, month and day.
+  pub fn started(mut self, date: ReleaseDate) -> Result<Self> {
+    self.started = FieldUpdate::Set(full_date(date)?);
+    Ok(self)
+  }
+
+  #[must_use]
+  pub fn remove_started(mut self) -> Self {
+    self.started = FieldUpdate::Remove;
+    self
+  }
+
+  /// # Errors
+  ///
+  /// Fails unless the date has a year, month and day.
+  pub fn finished(mut self, date: ReleaseDate) -> Result<Self> {
+    self.finished = FieldUpdate::Set(full_date(date)?);
+    Ok(self)
+  }
+
+  #[must_use]
+  pub fn remove_finished(mut self) -> Self {
+    self.finished = FieldUpdate::Remove;
+    self
+  }
+
+  /// Adds labels to the entry, keeping the ones it already has.
+  ///
+  /// # Errors
+  ///
+  /// Fails if one of the labels is [`Voted`](UlistBuiltinLabel::Voted), which VNDB manages.
+  pub fn labels_set<I>(mut self, labels: I) -> Result<Self>
+  where
+    I: IntoIterator<Item = u32>,
+  {
+    self
+      .labels_set
+      .extend(settable_labels(labels)?);
+    Ok(self)
+  }
+
+  /// Removes labels from the entry.
+  ///
+  /// # Errors
+  ///
+  /// Fails if one of the labels is [`Voted`](UlistBuiltinLabel::Voted), which VNDB manages.
+  pub fn labels_unset<I>(mut self, labels: I) -> Result<Self>
+  where
+    I: IntoIterator<Item = u32>,
+  {
+    self
+      .labels_unset
+      .extend(settable_labels(labels)?);
+    Ok(self)
+  }
+
+  #[must_use]
+  pub fn set_label(mut self, label: UlistSettableLabel) -> Self {
+    self.labels_set.push(label.id());
+    self
+  }
+
+  #[must_use]
+  pub fn unset_label(mut self, label: UlistSettableLabel) -> Self {
+    self.labels_unset.push(label.id());
+    self
+  }
+}
+
+fn full_date(date: ReleaseDate) -> Result<ReleaseDate> {
+  if date.is_full() {
+    Ok(date)
+  } else {
+    Err(Error::InvalidReleaseDate(date.to_string()))
+  }
+}
+
+fn settable_labels<I>(labels: I) -> Result<Vec<u32>>
+where
+  I: IntoIterator<Item = u32>,
+{
+  labels
+    .into_iter()
+    .map(|id| match UlistBuiltinLabel::from_id(id) {
+      Some(label) => UlistSettableLabel::try_from(label).map(UlistSettableLabel::id),
+      None => Ok(id),
+    })
+    .collect()
+}
+
+// A field of an update: left out while untouched, sent as `null` once removed.
+#[derive(Clone, Debug, Default)]
+enum FieldUpdate<T> {
+  #[default]
+  Keep,
+  Remove,
+  Set(T),
+}
+
+impl<T> FieldUpdate<T> {
+  fn is_keep(&self) -> bool {
+    matches!(self, Self::Keep)
+  }
+}
+
+impl<T: Serialize> Serialize for FieldUpdate<T> {
+  fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Set(value) => value.serialize(serializer),
+      Self::Keep | Self::Remove => serializer.serialize_none(),
+    }
+  }
+}
+
+/// A vote from 10 to 100, shown on the site as 1.0 to 10.0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(try_from = "u8", into = "u8")]
+pub struct Vote(u8);
+
+impl Vote {
+  pub const MIN: Self = Self(10);
+  pub const MAX: Self = Self(100);
+
+  pub fn new(vote: u8) -> Option<Self> {
+    (Self::MIN.0..=Self::MAX.0)
+      .contains(&vote)
+      .then_some(Self(vote))
+  }
+
+  pub fn get(self) -> u8 {
+    self.0
+  }
+}
+
+impl TryFrom<u8> for Vote {
+  type Error = Error;
+
+  fn try_from(vote: u8) -> Result<Self> {
+    Self::new(vote).ok_or(Error::InvalidVote(vote))
+  }
+}
+
+impl From<Vote> for u8 {
+  fn from(vote: Vote) -> Self {
+    vote.0
+  }
+}
+
+/// See: <https://api.vndb.org/kana#get-ulist_labels>
+#[remain::sorted]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UlistLabel {
+  /// Number of visual novels with this label. Only present if requested.
+  pub count: Option<u32>,
+  pub id: u32,
+  pub label: String,
+  pub private: bool,
+}
+
+impl UlistLabel {
+  /// The built-in label with this id, if any.
+  pub fn builtin(&self) -> Option<UlistBuiltinLabel> {
+    UlistBuiltinLabel::from_id(self.id)
+  }
+
+  pub fn is_custom(&self) -> bool {
+    self.builtin().is_none()
+  }
+}
+
+/// Labels every user has. Custom labels start at id 10.
+#[non_exhaustive]
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, FromRepr, VariantArray)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UlistBuiltinLabel {
+  Playing = 1,
+  Finished = 2,
+  Stalled = 3,
+  Dropped = 4,
+  Wishlist = 5,
+  Blacklist = 6,
+  /// Managed by VNDB, applied to every visual novel with a vote.
+  Voted = 7,
+}
+
+impl UlistBuiltinLabel {
+  pub fn from_id(id: u32) -> Option<Self> {
+    Self::from_repr(id)
+  }
+
+  pub fn id(self) -> u32 {
+    self as u32
+  }
+}
+
+/// Built-in labels that can be set or unset through [`UlistUpdate`].
+/// [`Voted`](UlistBuiltinLabel::Voted) is left out, as VNDB manages it.
+#[non_exhaustive]
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, FromRepr, VariantArray)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UlistSettableLabel {
+  Playing = 1,
+  Finished = 2,
+  Stalled = 3,
+  Dropped = 4,
+  Wishlist = 5,
+  Blacklist = 6,
+}
+
+impl UlistSettableLabel {
+  pub fn id(self) -> u32 {
+    self as u32
+  }
+}
+
+impl From<UlistSettableLabel> for UlistBuiltinLabel {
+  fn from(label: UlistSettableLabel) -> Self {
+    match label {
+      UlistSettableLabel::Playing => Self::Playing,
+      UlistSettableLabel::Finished => Self::Finished,
+      UlistSettableLabel::Stalled => Self::Stalled,
+      UlistSettableLabel::Dropped => Self::Dropped,
+      UlistSettableLabel::Wishlist => Self::Wishlist,
+      UlistSettableLabel::Blacklist => Self::Blacklist,
+    }
+  }
+}
+
+impl TryFrom<UlistBuiltinLabel> for UlistSettableLabel {
+  type Error = Error;
+
+  fn try_from(label: UlistBuiltinLabel) -> Result<Self> {
+    Self::from_repr(label.id()).ok_or(Error::ReadOnlyLabel(label))
+  }
+}
+
+/// Labels of a user's list, in the order returned by the API.
+#[derive(Clone, Debug, Default, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UlistLabels(Vec<UlistLabel>);
+
+impl<'de> Deserialize<'de> for UlistLabels {
+  fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    // The API wraps the labels in an object, but only the list gets serialized.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Labels {
+      List(Vec<UlistLabel>),
+      Object { labels: Vec<UlistLabel> },
+    }
+
+    Ok(match Labels::deserialize(deserializer)? {
+      Labels::List(labels) | Labels::Object { labels } => Self(labels),
+    })
+  }
+}
+
+impl UlistLabels {
+  pub fn builtin(&self, label: UlistBuiltinLabel) -> Option<&UlistLabel> {
+    self.0.iter().find(|it| it.id == label.id())
+  }
+
+  pub fn into_inner(self) -> Vec<UlistLabel> {
+    self.0
+  }
+}
+
+impl Deref for UlistLabels {
+  type Target = Vec<UlistLabel>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for UlistLabels {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+impl IntoIterator for UlistLabels {
+  type Item = UlistLabel;
+  type IntoIter = std::vec::IntoIter<Self::Item>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.0.into_iter()
+  }
+}
+
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString, VariantArray)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UlistLabelField {
+  #[serde(rename = "count")]
+  #[strum(serialize = "count")]
+  Count,
+}
+
+impl QueryField for UlistLabelField {}
+
+impl_into_field_set!(UlistLabelField);
@@ -4,6 +4,7 @@ use crate::{
   impl_id_newtype, impl_into_field_set, impl_string_set, impl_string_set_from_newtype,
   impl_string_set_from_numeric,
 };
+use itertools::Itertools;
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -120,6 +121,7 @@ impl UserUrlQuery {
       self
         .0
         .into_iter()
+        .sorted()
         .map(|user| (Self::URL_QUERY_PARAM, user))
         .collect(),
     )
@@ -1,14 +1,21 @@
-use super::character::Character;
-use super::producer::Producer;
-use super::release::ExternalLink;
-use super::schema::Language;
-use super::staff::Staff;
-use super::tag::Tag;
+use super::character::{Character, CharacterField, CharacterFilter};
+use super::date::ReleaseDate;
+use super::extlink::ExternalLink;
+use super::language::Language;
+use super::platform::Platform;
+use super::producer::{Producer, ProducerField, ProducerFilter};
+use super::release::{Release, ReleaseField, ReleaseFilter};
+use super::spoiler::SpoilerLevel;
+use super::staff::{Staff, StaffField, StaffFilter, StaffRole};
+use super::tag::{Tag, TagField, TagId};
 use super::{QueryField, SortQueryBy};
-use crate::{impl_id_newtype, impl_into_field_set};
+use crate::http::{Comparison, Equality, FieldSet};
+use crate::{
+  impl_filter, impl_filter_value, impl_id_newtype, impl_into_field_set, impl_nested_fields,
+  impl_numeric_enum, impl_str_serde,
+};
 use regex::Regex;
-use serde::{Deserialize, Deserializer, Serialize};
-use std::result::Result as StdResult;
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, LazyLock};
 use strum::{Display, EnumIs, EnumString, VariantArray};
 
@@ -28,15 +35,19 @@ pub struct VisualNovel {
   pub extlinks: Option<Vec<ExternalLink>>,
   pub id: VisualNovelId,
   pub image: Option<VisualNovelImage>,
+  #[cfg_attr(feature = "specta", specta(type = Option<Vec<String>>))]
   pub languages: Option<Vec<Language>>,
   pub length: Option<VisualNovelLength>,
   pub length_minutes: Option<u32>,
   pub length_votes: Option<u32>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub olang: Option<Language>,
-  pub platforms: Option<Vec<String>>,
+  #[cfg_attr(feature = "specta", specta(type = Option<Vec<String>>))]
+  pub platforms: Option<Vec<Platform>>,
   pub rating: Option<f32>,
   pub relations: Option<Vec<VisualNovelRelation>>,
-  pub released: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub released: Option<ReleaseDate>,
   pub screenshots: Option<Vec<VisualNovelScreenShot>>,
   pub staff: Option<Vec<VisualNovelStaff>>,
   pub tags: Option<Vec<VisualNovelTag>>,
@@ -46,6 +57,18 @@ pub struct VisualNovel {
   pub votecount: Option<u32>,
 }
 
+impl VisualNovel {
+  /// Removes the tags that would spoil more than `max`, along with the tags marked as lies.
+  #[must_use]
+  pub fn without_spoilers(mut self, max: SpoilerLevel) -> Self {
+    if let Some(tags) = &mut self.tags {
+      tags.retain(|tag| tag.is_visible(max));
+    }
+
+    self
+  }
+}
+
 impl From<VisualNovel> for VisualNovelId {
   fn from(v: VisualNovel) -> Self {
     v.id
@@ -81,22 +104,15 @@ pub enum VisualNovelDevStatus {
   Cancelled,
   Finished,
   InDevelopment,
+  #[serde(untagged)]
+  Unknown(u64),
 }
 
-impl<'de> Deserialize<'de> for VisualNovelDevStatus {
-  fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
-  where
-    D: Deserializer<'de>,
-  {
-    use serde::de::Error;
-    match u8::deserialize(deserializer)? {
-      0 => Ok(Self::Finished),
-      1 => Ok(Self::InDevelopment),
-      2 => Ok(Self::Cancelled),
-      _ => Err(Error::custom("invalid devstatus")),
-    }
-  }
-}
+impl_numeric_enum!(VisualNovelDevStatus {
+  0 => Finished,
+  1 => InDevelopment,
+  2 => Cancelled,
+}, Unknown);
 
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -117,6 +133,7 @@ impl From<VisualNovelDeveloper> for Producer {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct VisualNovelEdition {
   pub eid: Option<u32>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub lang: Option<Language>,
   pub name: Option<String>,
   pub official: Option<bool>,
@@ -145,30 +162,24 @@ pub enum VisualNovelLength {
   Average,
   Long,
   VeryLong,
+  #[serde(untagged)]
+  Unknown(u64),
 }
 
-impl<'de> Deserialize<'de> for VisualNovelLength {
-  fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
-  where
-    D: Deserializer<'de>,
-  {
-    use serde::de::Error;
-    match u8::deserialize(deserializer)? {
-      1 => Ok(Self::VeryShort),
-      2 => Ok(Self::Short),
-      3 => Ok(Self::Average),
-      4 => Ok(Self::Long),
-      5 => Ok(Self::VeryLong),
-      _ => Err(D::Error::custom("invalid visual novel length")),
-    }
-  }
-}
+impl_numeric_enum!(VisualNovelLength {
+  1 => VeryShort,
+  2 => Short,
+  3 => Average,
+  4 => Long,
+  5 => VeryLong,
+}, Unknown);
 
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct VisualNovelRelation {
-  pub relation: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub relation: Option<RelationType>,
   pub relation_official: Option<bool>,
   #[serde(flatten)]
   pub visual_novel: VisualNovel,
@@ -180,12 +191,90 @@ impl From<VisualNovelRelation> for VisualNovel {
   }
 }
 
+/// How two visual novels are related.
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Display, EnumIs, EnumString)]
+pub enum RelationType {
+  #[strum(serialize = "alt")]
+  Alternative,
+
+  #[strum(serialize = "fan")]
+  Fandisc,
+
+  #[strum(serialize = "orig")]
+  Original,
+
+  #[strum(serialize = "par")]
+  Parent,
+
+  #[strum(serialize = "preq")]
+  Prequel,
+
+  #[strum(serialize = "ser")]
+  SameSeries,
+
+  #[strum(serialize = "set")]
+  SameSetting,
+
+  #[strum(serialize = "seq")]
+  Sequel,
+
+  #[strum(serialize = "char")]
+  SharesCharacters,
+
+  #[strum(serialize = "side")]
+  SideStory,
+
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl RelationType {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Alternative => "Alternative version",
+      Self::Fandisc => "Fandisc",
+      Self::Original => "Original game",
+      Self::Parent => "Parent story",
+      Self::Prequel => "Prequel",
+      Self::SameSeries => "Same series",
+      Self::SameSetting => "Same setting",
+      Self::Sequel => "Sequel",
+      Self::SharesCharacters => "Shares characters",
+      Self::SideStory => "Side story",
+      Self::Unknown(code) => code,
+    }
+  }
+
+  /// The relation as seen from the other visual novel, e.g. the inverse of a sequel is a prequel.
+  #[must_use]
+  pub fn inverse(&self) -> Self {
+    match self {
+      Self::Alternative => Self::Alternative,
+      Self::Fandisc => Self::Original,
+      Self::Original => Self::Fandisc,
+      Self::Parent => Self::SideStory,
+      Self::Prequel => Self::Sequel,
+      Self::SameSeries => Self::SameSeries,
+      Self::SameSetting => Self::SameSetting,
+      Self::Sequel => Self::Prequel,
+      Self::SharesCharacters => Self::SharesCharacters,
+      Self::SideStory => Self::Parent,
+      Self::Unknown(code) => Self::Unknown(code.clone()),
+    }
+  }
+}
+
+impl_str_serde!(RelationType);
+
 #[remain::sorted]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct VisualNovelScreenShot {
   #[serde(flatten)]
   pub image: VisualNovelImage,
+  pub release: Option<Release>,
 }
 
 impl From<VisualNovelScreenShot> for VisualNovelImage {
@@ -200,7 +289,8 @@ impl From<VisualNovelScreenShot> for VisualNovelImage {
 pub struct VisualNovelStaff {
   pub eid: Option<u32>,
   pub note: Option<String>,
-  pub role: Option<String>,
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+  pub role: Option<StaffRole>,
   #[serde(flatten)]
   pub staff: Staff,
 }
@@ -217,11 +307,22 @@ impl From<VisualNovelStaff> for Staff {
 pub struct VisualNovelTag {
   pub lie: Option<bool>,
   pub rating: Option<f32>,
-  pub spoiler: Option<u8>,
+  pub spoiler: Option<SpoilerLevel>,
   #[serde(flatten)]
   pub tag: Tag,
 }
 
+impl VisualNovelTag {
+  /// Whether the tag can be shown without going over `max`.
+  /// Tags marked as lies are never shown, and those without a spoiler level always are.
+  pub fn is_visible(&self, max: SpoilerLevel) -> bool {
+    self.lie != Some(true)
+      && self
+        .spoiler
+        .is_none_or(|spoiler| spoiler <= max)
+  }
+}
+
 impl From<VisualNovelTag> for Tag {
   fn from(v: VisualNovelTag) -> Self {
     v.tag
@@ -232,6 +333,7 @@ impl From<VisualNovelTag> for Tag {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct VisualNovelTitle {
+  #[cfg_attr(feature = "specta", specta(type = Option<String>))]
   pub lang: Option<Language>,
   pub latin: Option<String>,
   pub main: Option<bool>,
@@ -518,6 +620,49 @@ impl QueryField for VisualNovelField {}
 
 impl_into_field_set!(VisualNovelField);
 
+impl_nested_fields!(VisualNovelField {
+  developers => "developers": ProducerField,
+  screenshots_release => "screenshots.release": ReleaseField,
+  staff => "staff": StaffField,
+  tags => "tags": TagField,
+  va_character => "va.character": CharacterField,
+  va_staff => "va.staff": StaffField,
+});
+
+impl VisualNovelField {
+  /// Fields of the related visual novels nested in `relations`.
+  ///
+  /// `relations.id` is always requested, as the nested model needs it. Only the fields of the
+  /// visual novels themselves can be nested, so fields of other entities are left out,
+  /// e.g. `va.character.id` never becomes `relations.va.character.id`.
+  pub fn relations<F>(fields: F) -> FieldSet<Self>
+  where
+    F: Into<FieldSet<Self>>,
+  {
+    // Prefixes of the fields that belong to a related entity rather than the visual novel.
+    const RELATED: [&str; 6] = [
+      "developers.",
+      "relations.",
+      "screenshots.release.",
+      "staff.",
+      "tags.",
+      "va.",
+    ];
+
+    let fields = fields.into();
+    let fields = fields
+      .iter_raw()
+      .filter(|field| {
+        !RELATED
+          .iter()
+          .any(|prefix| field.starts_with(prefix))
+      })
+      .map(str::to_owned);
+
+    FieldSet::nested("relations", FieldSet::<Self>::from_raw(fields))
+  }
+}
+
 #[non_exhaustive]
 #[remain::sorted]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, Display, EnumString)]
@@ -549,3 +694,68 @@ pub enum SortVisualNovelBy {
 }
 
 impl SortQueryBy for SortVisualNovelBy {}
+
+/// See: <https://api.vndb.org/kana#vn-filters>
+#[non_exhaustive]
+#[remain::sorted]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VisualNovelFilter {
+  And(Vec<Self>),
+  AnimeId(Equality, u32),
+  Character(Equality, Box<CharacterFilter>),
+  Developer(Equality, Box<ProducerFilter>),
+  DevStatus(Equality, VisualNovelDevStatus),
+  /// Only matches tags applied directly to the visual novel, not their parents.
+  DirectTag(Equality, TagId),
+  HasAnime(bool),
+  HasDescription(bool),
+  HasReview(bool),
+  HasScreenshot(bool),
+  Id(Comparison, VisualNovelId),
+  /// Label id in the list of the user given to the query, or the owner of the token.
+  Label(Equality, u32),
+  Language(Equality, Language),
+  Length(Comparison, VisualNovelLength),
+  Or(Vec<Self>),
+  OriginalLanguage(Equality, Language),
+  Platform(Equality, Platform),
+  /// Bayesian rating, from 10 to 100.
+  Rating(Comparison, u8),
+  Release(Equality, Box<ReleaseFilter>),
+  Released(Comparison, ReleaseDate),
+  Search(Equality, String),
+  Staff(Equality, Box<StaffFilter>),
+  Tag(Equality, TagId),
+  VoteCount(Comparison, u32),
+}
+
+impl_filter!(VisualNovelFilter {
+  values: {
+    AnimeId => "anime_id",
+    Character => "character",
+    Developer => "developer",
+    DevStatus => "devstatus",
+    DirectTag => "dtag",
+    Id => "id",
+    Label => "label",
+    Language => "lang",
+    Length => "length",
+    OriginalLanguage => "olang",
+    Platform => "platform",
+    Rating => "rating",
+    Release => "release",
+    Released => "released",
+    Search => "search",
+    Staff => "staff",
+    Tag => "tag",
+    VoteCount => "votecount",
+  },
+  flags: {
+    HasAnime => "has_anime",
+    HasDescription => "has_description",
+    HasReview => "has_review",
+    HasScreenshot => "has_screenshot",
+  },
+});
+
+impl_filter_value!(VisualNovelId);
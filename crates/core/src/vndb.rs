@@ -1,29 +1,54 @@
 use crate::error::{Error, Result};
+use crate::http::request::DEFAULT_BASE_URL;
+use crate::http::request::delete::Delete;
 use crate::http::request::get::Get;
+use crate::http::request::patch::Patch;
 use crate::http::request::post::prelude::*;
-use crate::model::character::CharacterId;
-use crate::model::producer::ProducerId;
-use crate::model::release::ReleaseId;
-use crate::model::staff::StaffId;
-use crate::model::tag::TagId;
-use crate::model::r#trait::TraitId;
-use crate::model::user::{User, UserField, UserId};
-use crate::model::visual_novel::VisualNovelId;
+use crate::http::{
+  Cache, Equality, FieldSet, JsonQueryFilter, RateLimit, RateLimiter, ReqwestTransport,
+  RetryPolicy, Transport,
+};
+use crate::model::Batch;
+use crate::model::auth_info::{AuthInfo, TokenPermission};
+use crate::model::character::{Character, CharacterField, CharacterId};
+use crate::model::extlink::{ExtlinkId, ExtlinkQuery, ExtlinkSite};
+use crate::model::producer::{Producer, ProducerField, ProducerFilter, ProducerId};
+use crate::model::release::{Release, ReleaseField, ReleaseFilter, ReleaseId};
+use crate::model::staff::{Staff, StaffField, StaffFilter, StaffId};
+use crate::model::tag::{Tag, TagField, TagId};
+use crate::model::r#trait::{Trait, TraitField, TraitId};
+use crate::model::user::{User, UserField, UserId, UserUrlQuery};
+use crate::model::visual_novel::{VisualNovel, VisualNovelField, VisualNovelId};
+use futures::future::try_join_all;
+use itertools::Itertools;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::num::NonZeroU8;
 use std::ops::Deref;
 use std::sync::{Arc, Weak};
 use std::time::Duration;
-use tokio::sync::Semaphore;
+use tokio::sync::{OnceCell, Semaphore};
+use url::Url;
 
 const CONCURRENCY: NonZeroU8 = NonZeroU8::new(10).unwrap();
 
+// Maximum number of results the API returns per page.
+const BATCH_SIZE: u8 = 100;
+
 #[derive(Debug)]
 pub struct Vndb {
   pub(crate) semaphore: Arc<Semaphore>,
+  pub(crate) base_url: Url,
+  pub(crate) transport: Arc<dyn Transport>,
   pub(crate) token: Option<Token>,
   pub(crate) delay: Option<Duration>,
   pub(crate) timeout: Option<Duration>,
   pub(crate) user_agent: Option<String>,
+  pub(crate) retry: Option<RetryPolicy>,
+  pub(crate) rate_limiter: Option<RateLimiter>,
+  pub(crate) cache: Option<Cache>,
+  pub(crate) auth_info: OnceCell<AuthInfo>,
 }
 
 impl Vndb {
@@ -32,10 +57,16 @@ impl Vndb {
     let semaphore = Semaphore::new(concurrent_requests);
     Arc::new(Self {
       semaphore: Arc::new(semaphore),
+      base_url: DEFAULT_BASE_URL.clone(),
+      transport: Arc::new(ReqwestTransport::new()),
       token: None,
       delay: None,
       timeout: None,
       user_agent: None,
+      retry: None,
+      rate_limiter: None,
+      cache: None,
+      auth_info: OnceCell::new(),
     })
   }
 
@@ -55,9 +86,42 @@ impl Vndb {
     Post::new(Arc::downgrade(self))
   }
 
+  pub fn patch(self: &Arc<Self>) -> Patch {
+    Patch::new(Arc::downgrade(self))
+  }
+
+  pub fn delete(self: &Arc<Self>) -> Delete {
+    Delete::new(Arc::downgrade(self))
+  }
+
+  /// Removes every cached response, if a cache was configured.
+  pub async fn clear_cache(&self) {
+    if let Some(cache) = &self.cache {
+      cache.clear().await;
+    }
+  }
+
   pub(crate) fn upgrade(weak: &Weak<Self>) -> Result<Arc<Self>> {
     weak.upgrade().ok_or(Error::Disconnected)
   }
+
+  /// Fails unless the token has the given permission.
+  /// The token info is only fetched once and reused afterwards.
+  pub(crate) async fn require_permission(
+    self: &Arc<Self>,
+    permission: TokenPermission,
+  ) -> Result<()> {
+    let auth_info = self
+      .auth_info
+      .get_or_try_init(|| async { self.get().auth_info().await })
+      .await?;
+
+    if auth_info.permissions.contains(&permission) {
+      Ok(())
+    } else {
+      Err(Error::MissingPermission(permission))
+    }
+  }
 }
 
 macro_rules! find {
@@ -70,6 +134,36 @@ macro_rules! find {
   }};
 }
 
+// Duplicate ids are dropped, and the rest are split into chunks that are sent concurrently.
+macro_rules! find_many {
+  ($vndb:expr, $ids:expr, $fields:expr, $post_fn:ident, $field:ident) => {{
+    let ids = $ids.into_iter().unique().collect_vec();
+    let mut fields: FieldSet<$field> = $fields.into();
+    fields.insert(&$field::Id);
+
+    let requests = ids
+      .chunks(usize::from(BATCH_SIZE))
+      .map(|chunk| {
+        $vndb
+          .post()
+          .$post_fn()
+          .filters(id_filters(chunk))
+          .fields(fields.clone())
+          .results(BATCH_SIZE)
+          .send()
+      });
+
+    let found = try_join_all(requests)
+      .await?
+      .into_iter()
+      .flatten()
+      .map(|it| (it.id.clone(), it))
+      .collect();
+
+    Ok(Batch::new(ids, found))
+  }};
+}
+
 macro_rules! search {
   ($vndb:expr, $query:expr, $post_fn:ident, $field:ident) => {{
     let query = $query.as_ref();
@@ -86,26 +180,130 @@ impl Vndb {
     find!(self, id, character, CharacterField)
   }
 
+  pub async fn find_characters<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<CharacterId, Character>>
+  where
+    I: IntoIterator<Item = CharacterId>,
+    F: Into<FieldSet<CharacterField>>,
+  {
+    find_many!(self, ids, fields, character, CharacterField)
+  }
+
   pub fn find_producer(self: &Arc<Self>, id: &ProducerId) -> ProducerQuery {
     find!(self, id, producer, ProducerField)
   }
 
+  /// Finds the producers linking to a page on an external site, such as a Wikidata entry.
+  pub fn find_producer_by_extlink(
+    self: &Arc<Self>,
+    site: ExtlinkSite,
+    id: impl Into<ExtlinkId>,
+  ) -> ProducerQuery {
+    let query = ExtlinkQuery::Id(site, id.into());
+    let filters = ProducerFilter::Extlink(Equality::Eq, query);
+    self
+      .post()
+      .producer()
+      .filters(filters.into())
+  }
+
+  pub async fn find_producers<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<ProducerId, Producer>>
+  where
+    I: IntoIterator<Item = ProducerId>,
+    F: Into<FieldSet<ProducerField>>,
+  {
+    find_many!(self, ids, fields, producer, ProducerField)
+  }
+
   pub fn find_release(self: &Arc<Self>, id: &ReleaseId) -> ReleaseQuery {
     find!(self, id, release, ReleaseField)
   }
 
+  /// Finds the releases linking to a page on an external site, such as a Steam app id.
+  pub fn find_release_by_extlink(
+    self: &Arc<Self>,
+    site: ExtlinkSite,
+    id: impl Into<ExtlinkId>,
+  ) -> ReleaseQuery {
+    let query = ExtlinkQuery::Id(site, id.into());
+    let filters = ReleaseFilter::Extlink(Equality::Eq, query);
+    self.post().release().filters(filters.into())
+  }
+
+  pub async fn find_releases<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<ReleaseId, Release>>
+  where
+    I: IntoIterator<Item = ReleaseId>,
+    F: Into<FieldSet<ReleaseField>>,
+  {
+    find_many!(self, ids, fields, release, ReleaseField)
+  }
+
   pub fn find_staff(self: &Arc<Self>, id: &StaffId) -> StaffQuery {
     find!(self, id, staff, StaffField)
   }
 
+  /// Finds the staff members linking to a page on an external site, such as a Wikidata entry.
+  pub fn find_staff_by_extlink(
+    self: &Arc<Self>,
+    site: ExtlinkSite,
+    id: impl Into<ExtlinkId>,
+  ) -> StaffQuery {
+    let query = ExtlinkQuery::Id(site, id.into());
+    let filters = StaffFilter::Extlink(Equality::Eq, query);
+    self.post().staff().filters(filters.into())
+  }
+
+  pub async fn find_staff_members<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<StaffId, Staff>>
+  where
+    I: IntoIterator<Item = StaffId>,
+    F: Into<FieldSet<StaffField>>,
+  {
+    find_many!(self, ids, fields, staff, StaffField)
+  }
+
   pub fn find_tag(self: &Arc<Self>, id: &TagId) -> TagQuery {
     find!(self, id, tag, TagField)
   }
 
+  pub async fn find_tags<I, F>(self: &Arc<Self>, ids: I, fields: F) -> Result<Batch<TagId, Tag>>
+  where
+    I: IntoIterator<Item = TagId>,
+    F: Into<FieldSet<TagField>>,
+  {
+    find_many!(self, ids, fields, tag, TagField)
+  }
+
   pub fn find_trait(self: &Arc<Self>, id: &TraitId) -> TraitQuery {
     find!(self, id, r#trait, TraitField)
   }
 
+  pub async fn find_traits<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<TraitId, Trait>>
+  where
+    I: IntoIterator<Item = TraitId>,
+    F: Into<FieldSet<TraitField>>,
+  {
+    find_many!(self, ids, fields, r#trait, TraitField)
+  }
+
   pub async fn find_user(self: &Arc<Self>, id: &UserId) -> Result<Option<User>> {
     let user = self
       .get()
@@ -118,10 +316,45 @@ impl Vndb {
     Ok(user)
   }
 
+  pub async fn find_users<I, F>(self: &Arc<Self>, ids: I, fields: F) -> Result<Batch<UserId, User>>
+  where
+    I: IntoIterator<Item = UserId>,
+    F: Into<FieldSet<UserField>>,
+  {
+    let ids = ids.into_iter().unique().collect_vec();
+    let fields: FieldSet<UserField> = fields.into();
+
+    let get = self.get();
+    let requests = ids
+      .chunks(usize::from(BATCH_SIZE))
+      .map(|chunk| get.user(UserUrlQuery::from(chunk), fields.clone()));
+
+    let found = try_join_all(requests)
+      .await?
+      .into_iter()
+      .flat_map(|users| users.into_inner().into_values())
+      .map(|user| (user.id.clone(), user))
+      .collect::<HashMap<_, _>>();
+
+    Ok(Batch::new(ids, found))
+  }
+
   pub fn find_visual_novel(self: &Arc<Self>, id: &VisualNovelId) -> VisualNovelQuery {
     find!(self, id, visual_novel, VisualNovelField)
   }
 
+  pub async fn find_visual_novels<I, F>(
+    self: &Arc<Self>,
+    ids: I,
+    fields: F,
+  ) -> Result<Batch<VisualNovelId, VisualNovel>>
+  where
+    I: IntoIterator<Item = VisualNovelId>,
+    F: Into<FieldSet<VisualNovelField>>,
+  {
+    find_many!(self, ids, fields, visual_novel, VisualNovelField)
+  }
+
   pub fn search_character(self: &Arc<Self>, query: impl AsRef<str>) -> CharacterQuery {
     search!(self, query, character, CharacterField)
   }
@@ -149,15 +382,45 @@ impl Vndb {
   pub fn search_visual_novel(self: &Arc<Self>, query: impl AsRef<str>) -> VisualNovelQuery {
     search!(self, query, visual_novel, VisualNovelField)
   }
+
+  /// Visual novels in the list of the given user.
+  /// Private entries are only included if the token belongs to that user.
+  pub fn user_list(self: &Arc<Self>, user: &UserId) -> UlistQuery {
+    self.post().ulist().user(user.clone())
+  }
+}
+
+fn id_filters<Id: Serialize>(ids: &[Id]) -> JsonQueryFilter {
+  let filters = if let [id] = ids {
+    serde_json::json!(["id", "=", id])
+  } else {
+    let filters = ids
+      .iter()
+      .map(|id| serde_json::json!(["id", "=", id]));
+
+    JsonValue::Array(
+      std::iter::once(JsonValue::from("or"))
+        .chain(filters)
+        .collect(),
+    )
+  };
+
+  JsonQueryFilter::new(filters)
 }
 
 #[derive(Debug)]
 pub struct VndbBuilder {
   max_concurrent_requests: NonZeroU8,
+  base_url: Url,
+  allow_http: bool,
+  transport: Option<Arc<dyn Transport>>,
   token: Option<Token>,
   delay: Option<Duration>,
   timeout: Option<Duration>,
   user_agent: Option<String>,
+  retry: Option<RetryPolicy>,
+  rate_limit: Option<RateLimit>,
+  cache: Option<Cache>,
 }
 
 impl VndbBuilder {
@@ -171,6 +434,38 @@ impl VndbBuilder {
     self
   }
 
+  /// Sets the base URL used by every request, e.g. a staging mirror or a local mock server.
+  /// Defaults to [`API_BASE_URL`](crate::http::API_BASE_URL).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the URL cannot be used as a base, such as `mailto:` or `data:` URLs.
+  #[must_use]
+  pub fn base_url(mut self, url: Url) -> Self {
+    assert!(
+      !url.cannot_be_a_base(),
+      "{url} cannot be used as a base url"
+    );
+    self.base_url = url;
+    self
+  }
+
+  /// Allows plain HTTP when the base URL points to a loopback address.
+  /// Any other host still requires HTTPS. Ignored if a custom transport is set.
+  #[must_use]
+  pub fn allow_http(mut self, allow: bool) -> Self {
+    self.allow_http = allow;
+    self
+  }
+
+  /// Replaces the default [`ReqwestTransport`], e.g. with a
+  /// [`FixtureTransport`](crate::http::FixtureTransport) to record and replay responses.
+  #[must_use]
+  pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+    self.transport = Some(Arc::new(transport));
+    self
+  }
+
   #[must_use]
   pub fn token(mut self, token: impl Into<Token>) -> Self {
     self.token = Some(token.into());
@@ -195,15 +490,48 @@ impl VndbBuilder {
     self
   }
 
+  /// Retries throttled and failed requests according to the given policy.
+  #[must_use]
+  pub fn retry(mut self, policy: RetryPolicy) -> Self {
+    self.retry = Some(policy);
+    self
+  }
+
+  /// Limits how many requests are sent over time. Throttled responses pause every request for a while.
+  /// Use [`RateLimit::vndb`] to match the limits published by VNDB.
+  #[must_use]
+  pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+    self.rate_limit = Some(limit);
+    self
+  }
+
+  /// Caches responses to read requests. See [`Cache`] for how entries expire.
+  #[must_use]
+  pub fn cache(mut self, cache: Cache) -> Self {
+    self.cache = Some(cache);
+    self
+  }
+
   pub fn build(self) -> Arc<Vndb> {
     let max_concurrent_requests = self.max_concurrent_requests.get();
     let semaphore = Semaphore::new(usize::from(max_concurrent_requests));
+    let transport = self.transport.unwrap_or_else(|| {
+      let transport = ReqwestTransport::new().allow_http(self.allow_http);
+      Arc::new(transport) as Arc<dyn Transport>
+    });
+
     let vndb = Vndb {
       semaphore: Arc::new(semaphore),
+      base_url: self.base_url,
+      transport,
       token: self.token,
       delay: self.delay,
       timeout: self.timeout,
       user_agent: self.user_agent,
+      retry: self.retry,
+      rate_limiter: self.rate_limit.map(RateLimiter::new),
+      cache: self.cache,
+      auth_info: OnceCell::new(),
     };
 
     Arc::new(vndb)
@@ -214,10 +542,16 @@ impl Default for VndbBuilder {
   fn default() -> Self {
     Self {
       max_concurrent_requests: CONCURRENCY,
+      base_url: DEFAULT_BASE_URL.clone(),
+      allow_http: false,
+      transport: None,
       token: None,
       delay: None,
       timeout: None,
       user_agent: None,
+      retry: None,
+      rate_limit: None,
+      cache: None,
     }
   }
 }
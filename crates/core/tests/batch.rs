@@ -0,0 +1,73 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use serde_json::json;
+use vn_core::{VisualNovelField, VisualNovelId};
+
+#[tokio::test]
+async fn find_many_by_id() {
+  let server = MockServer::start([MockResponse::json(&json!({
+    "results": [{ "id": "v1", "title": "One" }, { "id": "v3", "title": "Three" }],
+    "more": false,
+  }))])
+  .await;
+
+  let ids = [1, 2, 3, 1].map(|id| VisualNovelId::with_suffix(id).unwrap());
+  let batch = server
+    .vndb()
+    .find_visual_novels(ids, VisualNovelField::Title)
+    .await
+    .unwrap();
+
+  assert_eq!(batch.found.len(), 2);
+  assert_eq!(batch.missing, [VisualNovelId::with_suffix(2).unwrap()]);
+
+  let one = batch
+    .get(&VisualNovelId::with_suffix(1).unwrap())
+    .unwrap();
+
+  assert_eq!(one.title.as_deref(), Some("One"));
+
+  let body = server.requests()[0].json();
+  assert_eq!(body["results"], 100);
+  assert_eq!(
+    body["filters"],
+    json!([
+      "or",
+      ["id", "=", "v1"],
+      ["id", "=", "v2"],
+      ["id", "=", "v3"]
+    ])
+  );
+}
+
+#[tokio::test]
+async fn find_many_in_chunks() {
+  let page = || MockResponse::json(&json!({ "results": [], "more": false }));
+  let server = MockServer::start([page(), page()]).await;
+
+  let ids = (1..=150).map(|id| VisualNovelId::with_suffix(id).unwrap());
+  let batch = server
+    .vndb()
+    .find_visual_novels(ids, VisualNovelField::none())
+    .await
+    .unwrap();
+
+  assert!(batch.found.is_empty());
+  assert_eq!(batch.missing.len(), 150);
+
+  let mut chunks = server
+    .requests()
+    .iter()
+    .map(|request| {
+      request.json()["filters"]
+        .as_array()
+        .unwrap()
+        .len()
+        - 1
+    })
+    .collect::<Vec<_>>();
+
+  chunks.sort_unstable();
+  assert_eq!(chunks, [50, 100]);
+}
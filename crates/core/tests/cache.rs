@@ -0,0 +1,124 @@
+mod common;
+
+use common::{MockResponse, MockServer, auth_info, stats};
+use serde_json::json;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use vn_core::http::{Cache, DiskCache};
+use vn_core::{Endpoint, ReleaseId, RlistStatus, UserId};
+
+fn memory() -> Cache {
+  Cache::memory(NonZeroUsize::new(10).unwrap())
+}
+
+#[tokio::test]
+async fn cache_hits_skip_the_network() {
+  let server = MockServer::start([stats()]).await;
+  let vndb = server.builder().cache(memory()).build();
+
+  let first = vndb.get().stats().await.unwrap();
+  let second = vndb.get().stats().await.unwrap();
+  assert_eq!(first.vn, second.vn);
+  assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn cache_bypass_and_refresh() {
+  let response = || MockResponse::json(&json!({ "results": [{ "id": "v1" }], "more": false }));
+  let server = MockServer::start([response(), response(), response()]).await;
+  let vndb = server.builder().cache(memory()).build();
+
+  let query = vndb.post().visual_novel().results(1);
+  query.clone().send().await.unwrap();
+  query.clone().send().await.unwrap();
+  assert_eq!(server.requests().len(), 1);
+
+  query
+    .clone()
+    .no_cache()
+    .send()
+    .await
+    .unwrap();
+  assert_eq!(server.requests().len(), 2);
+
+  query
+    .clone()
+    .refresh_cache()
+    .send()
+    .await
+    .unwrap();
+  query.send().await.unwrap();
+  assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn cache_ttl_per_endpoint() {
+  let server = MockServer::start([stats(), stats()]).await;
+  let vndb = server
+    .builder()
+    .cache(memory().endpoint_ttl(Endpoint::Stats, Duration::ZERO))
+    .build();
+
+  vndb.get().stats().await.unwrap();
+  vndb.get().stats().await.unwrap();
+  assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn disk_cache_survives_the_client() {
+  let dir = std::env::temp_dir().join(format!("vn-core-cache-{}", std::process::id()));
+  let server = MockServer::start([stats()]).await;
+  let client = || {
+    server
+      .builder()
+      .cache(Cache::new(DiskCache::new(&dir)))
+      .build()
+  };
+
+  client().get().stats().await.unwrap();
+  let stats = client().get().stats().await.unwrap();
+  assert_eq!(stats.vn, 7);
+  assert_eq!(server.requests().len(), 1);
+
+  client().clear_cache().await;
+  assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+  fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn list_writes_invalidate_the_cache() {
+  let entries = || MockResponse::json(&json!({ "results": [{ "id": "v17" }], "more": false }));
+  let server = MockServer::start([
+    entries(),
+    auth_info(&["listwrite"]),
+    MockResponse::new(204, ""),
+    entries(),
+    entries(),
+  ])
+  .await;
+
+  let vndb = server
+    .builder()
+    .token("secret")
+    .cache(memory())
+    .build();
+
+  let user = UserId::new("u1").unwrap();
+  let query = vndb.user_list(&user);
+  query.clone().send().await.unwrap();
+  query.clone().send().await.unwrap();
+  assert_eq!(server.requests().len(), 1);
+
+  let id = ReleaseId::new("r1").unwrap();
+  vndb
+    .patch()
+    .rlist(&id, RlistStatus::Obtained)
+    .await
+    .unwrap();
+
+  query.clone().send().await.unwrap();
+  query.send().await.unwrap();
+  assert_eq!(server.requests().len(), 4);
+}
@@ -1,6 +1,10 @@
+mod common;
+
+use serde_json::json;
 use std::sync::LazyLock;
 use vn_core::CharacterField::*;
-use vn_core::{CharacterId, Vndb};
+use vn_core::http::Equality;
+use vn_core::{Character, CharacterFilter, CharacterId, CharacterRole, SpoilerLevel, VisualNovel};
 
 const YUKARI: &str = "Kaburagi Yukari";
 const YUKARI_JP: &str = "鏑木 紫";
@@ -12,7 +16,7 @@ static YUKARI_ID: LazyLock<CharacterId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_character() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "c81501"]"#;
   let character = vndb
     .post()
@@ -51,8 +55,8 @@ async fn get_character() {
 
 #[tokio::test]
 async fn find_character() {
-  let character = Vndb::new()
-    .find_character(&*YUKARI_ID)
+  let character = common::vndb()
+    .find_character(&YUKARI_ID)
     .fields([Name, Original])
     .send()
     .await
@@ -68,7 +72,7 @@ async fn find_character() {
 
 #[tokio::test]
 async fn search_character() {
-  let character = Vndb::new()
+  let character = common::vndb()
     .search_character(YUKARI.to_ascii_uppercase())
     .fields([Name, Original])
     .send()
@@ -76,9 +80,76 @@ async fn search_character() {
     .unwrap()
     .results
     .into_iter()
-    .find(|it| &it.id == &*YUKARI_ID)
+    .find(|it| it.id == *YUKARI_ID)
     .unwrap();
 
   assert_eq!(character.name.as_deref(), Some(YUKARI));
   assert_eq!(character.original.as_deref(), Some(YUKARI_JP));
 }
+
+#[test]
+fn spoiler_free_character() {
+  let character: Character = serde_json::from_value(json!({
+    "id": "c1",
+    "traits": [
+      { "id": "i1", "spoiler": 0, "lie": false },
+      { "id": "i2", "spoiler": 1, "lie": false },
+      { "id": "i3", "spoiler": 2, "lie": false },
+      { "id": "i4", "spoiler": 0, "lie": true },
+    ],
+    "vns": [
+      { "id": "v1", "role": "main", "spoiler": 0 },
+      { "id": "v2", "role": "cameo", "spoiler": 2 },
+    ],
+  }))
+  .unwrap();
+
+  let vns = character.vns.as_ref().unwrap();
+  assert_eq!(vns[0].role, Some(CharacterRole::Main));
+  assert_eq!(
+    vns[1].role,
+    Some(CharacterRole::Unknown("cameo".to_owned()))
+  );
+
+  let character = character.without_spoilers(SpoilerLevel::Minor);
+  let traits: Vec<_> = character
+    .traits
+    .unwrap()
+    .into_iter()
+    .map(|t| t.r#trait.id.to_string())
+    .collect();
+
+  assert_eq!(traits, ["i1", "i2"]);
+  assert_eq!(character.vns.unwrap().len(), 1);
+
+  assert!(SpoilerLevel::None < SpoilerLevel::Minor);
+  assert!(SpoilerLevel::Minor < SpoilerLevel::Major);
+  assert!(SpoilerLevel::Major < SpoilerLevel::Unknown(3));
+  assert_eq!(
+    serde_json::from_value::<SpoilerLevel>(json!(3)).unwrap(),
+    SpoilerLevel::Unknown(3)
+  );
+
+  let filter = CharacterFilter::Role(Equality::Eq, CharacterRole::Primary);
+  assert_eq!(filter.to_json(), json!(["role", "=", "primary"]));
+}
+
+#[test]
+fn spoiler_free_visual_novel() {
+  let visual_novel: VisualNovel = serde_json::from_value(json!({
+    "id": "v1",
+    "tags": [
+      { "id": "g1", "spoiler": 0, "lie": false },
+      { "id": "g2", "spoiler": 1, "lie": false },
+      { "id": "g3", "spoiler": 0, "lie": true },
+    ],
+  }))
+  .unwrap();
+
+  let tags = visual_novel
+    .without_spoilers(SpoilerLevel::None)
+    .tags
+    .unwrap();
+  assert_eq!(tags.len(), 1);
+  assert_eq!(tags[0].spoiler, Some(SpoilerLevel::None));
+}
@@ -0,0 +1,218 @@
+#![allow(dead_code)]
+
+use serde_json::json;
+use std::env;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+use vn_core::http::{FixtureTransport, ReqwestTransport};
+use vn_core::{Vndb, VndbBuilder};
+
+/// Client used by the tests that talk to the API.
+///
+/// Set `VN_FIXTURES=record` to save the API responses to `tests/fixtures`,
+/// or `VN_FIXTURES=replay` to run from those fixtures without network.
+pub fn vndb() -> Arc<Vndb> {
+  let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+  match env::var("VN_FIXTURES").as_deref() {
+    Ok("record") => {
+      let transport = FixtureTransport::record(dir, ReqwestTransport::new());
+      Vndb::builder().transport(transport).build()
+    }
+    Ok("replay") => {
+      let transport = FixtureTransport::replay(dir);
+      Vndb::builder().transport(transport).build()
+    }
+    _ => Vndb::new(),
+  }
+}
+
+/// A minimal HTTP server that answers each connection with the next canned response.
+pub struct MockServer {
+  url: Url,
+  requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+  pub async fn start<I>(responses: I) -> Self
+  where
+    I: IntoIterator<Item = MockResponse>,
+  {
+    let listener = TcpListener::bind("127.0.0.1:0")
+      .await
+      .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let responses = responses.into_iter().collect::<Vec<_>>();
+
+    let log = Arc::clone(&requests);
+    tokio::spawn(async move {
+      for response in responses {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let request = read_request(&mut stream).await;
+        log.lock().unwrap().push(request);
+        stream
+          .write_all(&response.into_bytes())
+          .await
+          .unwrap();
+        let _ = stream.shutdown().await;
+      }
+    });
+
+    let url = Url::parse(&format!("http://{addr}/kana")).unwrap();
+    Self { url, requests }
+  }
+
+  pub fn url(&self) -> Url {
+    self.url.clone()
+  }
+
+  /// A client builder already pointed at this server.
+  pub fn builder(&self) -> VndbBuilder {
+    Vndb::builder()
+      .base_url(self.url())
+      .allow_http(true)
+  }
+
+  pub fn vndb(&self) -> Arc<Vndb> {
+    self.builder().build()
+  }
+
+  pub fn requests(&self) -> Vec<MockRequest> {
+    self.requests.lock().unwrap().clone()
+  }
+}
+
+/// Response of `GET /stats`.
+pub fn stats() -> MockResponse {
+  MockResponse::json(&json!({
+    "chars": 1,
+    "producers": 2,
+    "releases": 3,
+    "staff": 4,
+    "tags": 5,
+    "traits": 6,
+    "vn": 7,
+  }))
+}
+
+/// Response of `GET /authinfo` for a token with the given permissions.
+pub fn auth_info(permissions: &[&str]) -> MockResponse {
+  MockResponse::json(&json!({
+    "id": "u1",
+    "username": "yorhel",
+    "permissions": permissions,
+  }))
+}
+
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+  pub method: String,
+  pub path: String,
+  pub headers: Vec<(String, String)>,
+  pub body: String,
+}
+
+impl MockRequest {
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(key, _)| key.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+  }
+
+  pub fn json(&self) -> serde_json::Value {
+    serde_json::from_str(&self.body).unwrap()
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+  status: u16,
+  headers: Vec<(String, String)>,
+  body: String,
+}
+
+impl MockResponse {
+  pub fn new(status: u16, body: impl Into<String>) -> Self {
+    Self {
+      status,
+      headers: Vec::new(),
+      body: body.into(),
+    }
+  }
+
+  pub fn json(value: &serde_json::Value) -> Self {
+    Self::new(200, value.to_string()).header("Content-Type", "application/json")
+  }
+
+  #[must_use]
+  pub fn header(mut self, name: &str, value: &str) -> Self {
+    self
+      .headers
+      .push((name.to_owned(), value.to_owned()));
+    self
+  }
+
+  fn into_bytes(self) -> Vec<u8> {
+    let mut response = format!("HTTP/1.1 {} Mock\r\n", self.status);
+    for (name, value) in &self.headers {
+      write!(response, "{name}: {value}\r\n").unwrap();
+    }
+
+    write!(response, "Content-Length: {}\r\n", self.body.len()).unwrap();
+    response.push_str("Connection: close\r\n\r\n");
+    response.push_str(&self.body);
+    response.into_bytes()
+  }
+}
+
+async fn read_request(stream: &mut TcpStream) -> MockRequest {
+  let mut buffer = Vec::new();
+  let mut chunk = [0; 1024];
+  let head_len = loop {
+    let read = stream.read(&mut chunk).await.unwrap();
+    buffer.extend_from_slice(&chunk[..read]);
+    if let Some(pos) = buffer
+      .windows(4)
+      .position(|w| w == b"\r\n\r\n")
+    {
+      break pos + 4;
+    }
+
+    assert!(
+      read > 0,
+      "connection closed before the request was complete"
+    );
+  };
+
+  let head = String::from_utf8_lossy(&buffer[..head_len]).into_owned();
+  let mut lines = head.lines();
+  let mut request_line = lines.next().unwrap().split_whitespace();
+  let method = request_line.next().unwrap().to_owned();
+  let path = request_line.next().unwrap().to_owned();
+
+  let headers = lines
+    .filter_map(|line| line.split_once(':'))
+    .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+    .collect::<Vec<_>>();
+
+  let content_length = headers
+    .iter()
+    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+    .and_then(|(_, value)| value.parse::<usize>().ok())
+    .unwrap_or(0);
+
+  while buffer.len() < head_len + content_length {
+    let read = stream.read(&mut chunk).await.unwrap();
+    assert!(read > 0, "connection closed before the body was complete");
+    buffer.extend_from_slice(&chunk[..read]);
+  }
+
+  let body = String::from_utf8_lossy(&buffer[head_len..head_len + content_length]).into_owned();
+  MockRequest { method, path, headers, body }
+}
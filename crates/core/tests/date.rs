@@ -0,0 +1,106 @@
+use serde_json::json;
+use vn_core::http::Comparison;
+use vn_core::{ReleaseDate, ReleaseFilter};
+
+#[test]
+fn parse_release_dates() {
+  let date: ReleaseDate = "2019-04-26".parse().unwrap();
+  assert_eq!(date, ReleaseDate::from_ymd(2019, 4, 26).unwrap());
+  assert_eq!(
+    (date.year(), date.month(), date.day()),
+    (Some(2019), Some(4), Some(26))
+  );
+
+  let date: ReleaseDate = "2019-04".parse().unwrap();
+  assert_eq!(date, ReleaseDate::from_ym(2019, 4).unwrap());
+  assert_eq!(date.day(), None);
+
+  let date: ReleaseDate = "2019".parse().unwrap();
+  assert_eq!(date, ReleaseDate::from_y(2019));
+  assert_eq!(date.month(), None);
+
+  let date: ReleaseDate = "TBA".parse().unwrap();
+  assert!(date.is_tba());
+  assert_eq!(date, ReleaseDate::TBA);
+  assert_eq!(date.year(), None);
+
+  for invalid in [
+    "",
+    "19",
+    "2019-4",
+    "2019-13",
+    "2019-02-30",
+    "2019-04-26-01",
+    "tomorrow",
+  ] {
+    assert!(invalid.parse::<ReleaseDate>().is_err(), "{invalid}");
+  }
+}
+
+#[test]
+fn release_dates_follow_vndb_order() {
+  let mut dates = [
+    "TBA",
+    "2019",
+    "2019-04",
+    "2019-04-26",
+    "2018-12-31",
+    "2019-05-01",
+  ]
+  .map(|date| date.parse::<ReleaseDate>().unwrap());
+
+  dates.sort();
+
+  let dates = dates.map(|date| date.to_string());
+  assert_eq!(
+    dates,
+    [
+      "2018-12-31",
+      "2019-04-26",
+      "2019-04",
+      "2019-05-01",
+      "2019",
+      "TBA"
+    ]
+  );
+
+  assert_eq!(ReleaseDate::from_ym(2019, 4).unwrap().code(), 20_190_499);
+  assert_eq!(ReleaseDate::TBA.code(), 99_999_999);
+}
+
+#[test]
+fn release_dates_are_strings() {
+  let date = ReleaseDate::from_ym(2019, 4).unwrap();
+  assert_eq!(serde_json::to_value(date).unwrap(), json!("2019-04"));
+  assert_eq!(
+    serde_json::from_value::<ReleaseDate>(json!("2019-04")).unwrap(),
+    date
+  );
+
+  let filter = ReleaseFilter::Released(Comparison::Ge, date);
+  assert_eq!(filter.to_json(), json!(["released", ">=", "2019-04"]));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_dates() {
+  let date = chrono::NaiveDate::from_ymd_opt(2019, 4, 26).unwrap();
+  let release = ReleaseDate::try_from(date).unwrap();
+  assert_eq!(release, ReleaseDate::from_ymd(2019, 4, 26).unwrap());
+  assert_eq!(chrono::NaiveDate::try_from(release).unwrap(), date);
+
+  let ancient = chrono::NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+  assert!(ReleaseDate::try_from(ancient).is_err());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_dates() {
+  let date = time::Date::from_calendar_date(2019, time::Month::April, 26).unwrap();
+  let release = ReleaseDate::try_from(date).unwrap();
+  assert_eq!(release, ReleaseDate::from_ymd(2019, 4, 26).unwrap());
+  assert_eq!(time::Date::try_from(release).unwrap(), date);
+
+  let ancient = time::Date::from_calendar_date(-44, time::Month::March, 15).unwrap();
+  assert!(ReleaseDate::try_from(ancient).is_err());
+}
@@ -1,4 +1,9 @@
+mod common;
+
+use common::{MockServer, stats};
 use strum::VariantArray;
+use url::Url;
+use vn_core::Vndb;
 use vn_core::http::Endpoint;
 
 #[test]
@@ -8,3 +13,37 @@ fn endpoints_are_valid() {
     endpoint.url();
   }
 }
+
+#[test]
+fn endpoint_url_with_base() {
+  let base = Url::parse("http://127.0.0.1:8080/kana/").unwrap();
+  let url = Endpoint::VisualNovel.url_with_base(&base);
+  assert_eq!(url.as_str(), "http://127.0.0.1:8080/kana/vn");
+
+  let base = Url::parse("https://example.com").unwrap();
+  let url = Endpoint::UlistLabels.url_with_base(&base);
+  assert_eq!(url.as_str(), "https://example.com/ulist_labels");
+}
+
+#[tokio::test]
+async fn custom_base_url() {
+  let server = MockServer::start([stats()]).await;
+  let stats = server.vndb().get().stats().await.unwrap();
+  assert_eq!(stats.vn, 7);
+
+  let requests = server.requests();
+  assert_eq!(requests.len(), 1);
+  assert_eq!(requests[0].method, "GET");
+  assert_eq!(requests[0].path, "/kana/stats");
+}
+
+#[tokio::test]
+async fn plain_http_requires_opt_in() {
+  let server = MockServer::start([stats()]).await;
+  let vndb = Vndb::builder()
+    .base_url(server.url())
+    .build();
+
+  assert!(vndb.get().stats().await.is_err());
+  assert!(server.requests().is_empty());
+}
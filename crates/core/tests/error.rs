@@ -0,0 +1,33 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use vn_core::error::Error;
+
+#[tokio::test]
+async fn api_error_variants() {
+  let server = MockServer::start([
+    MockResponse::new(400, "Invalid filter 'lang'.\n"),
+    MockResponse::new(401, "Invalid token."),
+    MockResponse::new(404, "Not found."),
+    MockResponse::new(429, "Throttled."),
+    MockResponse::new(502, "Bad gateway."),
+  ])
+  .await;
+
+  let vndb = server.vndb();
+
+  let error = vndb.get().stats().await.unwrap_err();
+  assert!(matches!(error, Error::BadRequest { message } if message == "Invalid filter 'lang'."));
+
+  let error = vndb.get().stats().await.unwrap_err();
+  assert!(matches!(error, Error::InvalidToken { message } if message == "Invalid token."));
+
+  let error = vndb.get().stats().await.unwrap_err();
+  assert!(error.is_not_found());
+
+  let error = vndb.get().stats().await.unwrap_err();
+  assert!(matches!(error, Error::Throttled { message } if message == "Throttled."));
+
+  let error = vndb.get().stats().await.unwrap_err();
+  assert!(matches!(error, Error::ServerError { status, .. } if status.as_u16() == 502));
+}
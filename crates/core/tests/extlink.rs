@@ -0,0 +1,64 @@
+use serde_json::json;
+use vn_core::http::Equality;
+use vn_core::{ExtlinkId, ExtlinkSite, ProducerFilter, Release, ReleaseFilter};
+
+#[test]
+fn typed_extlinks() {
+  let release: Release = serde_json::from_value(json!({
+    "id": "r1",
+    "extlinks": [
+      { "name": "steam", "id": 412_830 },
+      { "name": "dlsite", "id": "RJ123456" },
+      { "name": "newsite", "id": "abc" },
+    ],
+  }))
+  .unwrap();
+
+  // The old path still names the same type.
+  let extlinks: Vec<vn_core::model::release::ExternalLink> = release.extlinks.unwrap();
+  let links: Vec<_> = extlinks
+    .into_iter()
+    .map(|link| (link.name.unwrap(), link.id.unwrap()))
+    .collect();
+
+  assert_eq!(
+    links,
+    [
+      (ExtlinkSite::Steam, ExtlinkId::Number(412_830)),
+      (ExtlinkSite::Dlsite, ExtlinkId::Text("RJ123456".to_owned())),
+      (
+        ExtlinkSite::Unknown("newsite".to_owned()),
+        ExtlinkId::Text("abc".to_owned())
+      ),
+    ]
+  );
+}
+
+#[test]
+fn site_ids() {
+  assert!(ExtlinkSite::Steam.has_numeric_id());
+  assert!(!ExtlinkSite::Dlsite.has_numeric_id());
+  assert_eq!(ExtlinkSite::Steam.name(), "Steam");
+
+  assert_eq!(
+    ExtlinkSite::Steam.parse_id("412830"),
+    Some(ExtlinkId::Number(412_830))
+  );
+  assert_eq!(ExtlinkSite::Steam.parse_id("RJ123456"), None);
+  assert_eq!(
+    ExtlinkSite::Dlsite.parse_id("RJ123456"),
+    Some(ExtlinkId::Text("RJ123456".to_owned()))
+  );
+}
+
+#[test]
+fn extlink_filters() {
+  let filter = ReleaseFilter::Extlink(Equality::Eq, (ExtlinkSite::Steam, 412_830_u64).into());
+  assert_eq!(
+    filter.to_json(),
+    json!(["extlink", "=", ["steam", 412_830]])
+  );
+
+  let filter = ProducerFilter::Extlink(Equality::Ne, ExtlinkSite::Wikidata.into());
+  assert_eq!(filter.to_json(), json!(["extlink", "!=", "wikidata"]));
+}
@@ -0,0 +1,59 @@
+use serde_json::json;
+use vn_core::http::{Comparison, Equality, JsonQueryFilter};
+use vn_core::{
+  CharacterFilter, CharacterSexValue, Language, ReleaseFilter, ReleaseVoiced, StaffFilter,
+  VisualNovelFilter, VisualNovelId, VisualNovelLength,
+};
+
+#[test]
+fn simple_filters() {
+  let filter = VisualNovelFilter::Language(Equality::Eq, Language::English);
+  assert_eq!(filter.to_json(), json!(["lang", "=", "en"]));
+
+  let id = VisualNovelId::new("v17").unwrap();
+  let filter = VisualNovelFilter::Id(Comparison::Ge, id);
+  assert_eq!(filter.to_json(), json!(["id", ">=", "v17"]));
+
+  let filter = VisualNovelFilter::HasAnime(false);
+  assert_eq!(filter.to_json(), json!(["has_anime", "!=", 1]));
+}
+
+#[test]
+fn numeric_enums_use_their_code() {
+  let filter = VisualNovelFilter::Length(Comparison::Lt, VisualNovelLength::Long);
+  assert_eq!(filter.to_json(), json!(["length", "<", 4]));
+
+  let filter = ReleaseFilter::Voiced(Equality::Eq, ReleaseVoiced::FullyVoiced);
+  assert_eq!(filter.to_json(), json!(["voiced", "=", 4]));
+}
+
+#[test]
+fn combined_and_nested_filters() {
+  let filter = CharacterFilter::and([
+    CharacterFilter::Sex(Equality::Eq, CharacterSexValue::Female),
+    CharacterFilter::Birthday(Equality::Eq, (2, 14)),
+    CharacterFilter::Seiyuu(
+      Equality::Eq,
+      Box::new(StaffFilter::or([
+        StaffFilter::Language(Equality::Eq, Language::Japanese),
+        StaffFilter::IsMain(true),
+      ])),
+    ),
+  ]);
+
+  let expected = json!([
+    "and",
+    ["sex", "=", "f"],
+    ["birthday", "=", [2, 14]],
+    [
+      "seiyuu",
+      "=",
+      ["or", ["lang", "=", "ja"], ["ismain", "=", 1]]
+    ],
+  ]);
+
+  assert_eq!(filter.to_json(), expected);
+
+  let filter = JsonQueryFilter::from(filter);
+  assert_eq!(filter.into_inner(), expected);
+}
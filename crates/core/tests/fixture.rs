@@ -0,0 +1,43 @@
+mod common;
+
+use common::{MockServer, stats};
+use std::fs;
+use url::Url;
+use vn_core::Vndb;
+use vn_core::error::Error;
+use vn_core::http::{FixtureTransport, ReqwestTransport};
+
+#[tokio::test]
+async fn record_and_replay_fixtures() {
+  let dir = std::env::temp_dir().join(format!("vn-core-fixtures-{}", std::process::id()));
+  let server = MockServer::start([stats()]).await;
+
+  let transport = ReqwestTransport::new().allow_http(true);
+  let recorded = server
+    .builder()
+    .transport(FixtureTransport::record(&dir, transport))
+    .build()
+    .get()
+    .stats()
+    .await
+    .unwrap();
+
+  assert_eq!(recorded.vn, 7);
+  assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+  // The host does not exist, so the response can only come from the fixture.
+  // Fixtures are keyed on the path after the base URL, so the base path can differ too.
+  let base_url = Url::parse("https://vndb.invalid/api/v2").unwrap();
+  let replay = Vndb::builder()
+    .base_url(base_url.clone())
+    .transport(FixtureTransport::replay(&dir).base_url(&base_url))
+    .build();
+
+  let replayed = replay.get().stats().await.unwrap();
+  assert_eq!(replayed.vn, recorded.vn);
+
+  let missing = replay.get().schema().await;
+  assert!(matches!(missing, Err(Error::Io(_))));
+
+  fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,78 @@
+use serde_json::json;
+use vn_core::http::Equality;
+use vn_core::{Language, VisualNovel, VisualNovelFilter};
+
+#[test]
+fn regional_variants_are_kept() {
+  let visual_novel: VisualNovel = serde_json::from_value(json!({
+    "id": "v17",
+    "languages": ["zh-Hans", "zh-Hant", "pt-br", "pt-pt", "tlh"],
+  }))
+  .unwrap();
+
+  let languages = visual_novel.languages.unwrap();
+  assert_eq!(
+    languages,
+    [
+      Language::ChineseSimplified,
+      Language::ChineseTraditional,
+      Language::PortugueseBrazil,
+      Language::PortuguesePortugal,
+      Language::Unknown("tlh".to_owned()),
+    ]
+  );
+
+  assert_eq!(
+    serde_json::to_value(&languages).unwrap(),
+    json!(["zh-Hans", "zh-Hant", "pt-br", "pt-pt", "tlh"])
+  );
+}
+
+#[test]
+fn language_names_and_bases() {
+  assert_eq!(Language::PortugueseBrazil.name(), "Portuguese (Brazil)");
+  assert_eq!(Language::PortugueseBrazil.base(), "pt");
+  assert_eq!(Language::ChineseTraditional.base(), "zh");
+  assert!(Language::ChineseSimplified.same_base(&Language::Chinese));
+  assert!(!Language::PortuguesePortugal.same_base(&Language::Spanish));
+}
+
+#[test]
+fn bcp47_tags() {
+  assert_eq!(Language::PortugueseBrazil.bcp47(), "pt-BR");
+  assert_eq!(Language::Tagalog.bcp47(), "tl");
+  assert_eq!(Language::Cherokee.bcp47(), "chr");
+  assert_eq!(Language::English.bcp47(), "en");
+
+  let cases = [
+    ("en-US", Some(Language::English)),
+    ("ja", Some(Language::Japanese)),
+    ("zh", Some(Language::Chinese)),
+    ("zh-CN", Some(Language::ChineseSimplified)),
+    ("zh-Hant-CN", Some(Language::ChineseTraditional)),
+    ("zh_TW", Some(Language::ChineseTraditional)),
+    ("pt", Some(Language::PortugueseBrazil)),
+    ("pt-PT", Some(Language::PortuguesePortugal)),
+    ("fil", Some(Language::Tagalog)),
+    ("nb-NO", Some(Language::Norwegian)),
+    ("ta", None),
+    ("tlh", None),
+    ("", None),
+  ];
+
+  for (tag, language) in cases {
+    assert_eq!(Language::from_bcp47(tag), language, "{tag}");
+  }
+}
+
+#[test]
+fn language_filter() {
+  let filter = VisualNovelFilter::Language(Equality::Eq, Language::ChineseSimplified);
+  assert_eq!(filter.to_json(), json!(["lang", "=", "zh-Hans"]));
+}
+
+#[test]
+fn language_is_still_exported_from_schema() {
+  let language: vn_core::model::schema::Language = "en".parse().unwrap();
+  assert_eq!(language, Language::English);
+}
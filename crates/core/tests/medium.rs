@@ -0,0 +1,42 @@
+use serde_json::json;
+use vn_core::http::Equality;
+use vn_core::{Medium, Release, ReleaseFilter};
+
+#[test]
+fn release_media() {
+  let release: Release = serde_json::from_value(json!({
+    "id": "r1",
+    "media": [
+      { "medium": "cd", "qty": 2 },
+      { "medium": "in", "qty": 0 },
+      { "medium": "zzz", "qty": 1 },
+    ],
+  }))
+  .unwrap();
+
+  let media = release.media.unwrap();
+  assert_eq!(media[0].medium, Some(Medium::Cd));
+  assert_eq!(media[0].quantity(), Some(2));
+  assert_eq!(media[1].medium, Some(Medium::InternetDownload));
+  assert_eq!(media[1].quantity(), None);
+  assert_eq!(media[2].medium, Some(Medium::Unknown("zzz".to_owned())));
+
+  let media = serde_json::to_value(&media).unwrap();
+  assert_eq!(media[1], json!({ "medium": "in", "qty": 0 }));
+}
+
+#[test]
+fn medium_names_and_quantity() {
+  assert_eq!(Medium::BluRay.name(), "Blu-ray disc");
+  assert!(Medium::Dvd.has_quantity());
+  assert!(Medium::InternetDownload.is_digital());
+  assert!(!Medium::InternetDownload.has_quantity());
+  assert!(!Medium::Other.has_quantity());
+  assert!(!Medium::Other.is_digital());
+}
+
+#[test]
+fn medium_filter() {
+  let filter = ReleaseFilter::Medium(Equality::Ne, Medium::InternetDownload);
+  assert_eq!(filter.to_json(), json!(["medium", "!=", "in"]));
+}
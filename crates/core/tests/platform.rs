@@ -0,0 +1,45 @@
+use serde_json::json;
+use vn_core::http::Equality;
+use vn_core::{Platform, PlatformFamily, VisualNovel, VisualNovelFilter};
+
+#[test]
+fn platform_codes() {
+  let visual_novel: VisualNovel = serde_json::from_value(json!({
+    "id": "v17",
+    "platforms": ["win", "swi", "and", "zzz"],
+  }))
+  .unwrap();
+
+  let platforms = visual_novel.platforms.unwrap();
+  assert_eq!(
+    platforms,
+    [
+      Platform::Windows,
+      Platform::NintendoSwitch,
+      Platform::Android,
+      Platform::Unknown("zzz".to_owned()),
+    ]
+  );
+
+  assert_eq!(
+    serde_json::to_value(&platforms).unwrap(),
+    json!(["win", "swi", "and", "zzz"])
+  );
+}
+
+#[test]
+fn platform_names_and_families() {
+  assert_eq!(Platform::PlayStation4.name(), "PlayStation 4");
+  assert_eq!(Platform::Unknown("zzz".to_owned()).name(), "zzz");
+
+  assert_eq!(Platform::Linux.family(), PlatformFamily::Desktop);
+  assert_eq!(Platform::PlayStationVita.family(), PlatformFamily::Console);
+  assert_eq!(Platform::Ios.family(), PlatformFamily::Mobile);
+  assert_eq!(Platform::Website.family(), PlatformFamily::Other);
+}
+
+#[test]
+fn platform_filter() {
+  let filter = VisualNovelFilter::Platform(Equality::Eq, Platform::PlayStation4);
+  assert_eq!(filter.to_json(), json!(["platform", "=", "ps4"]));
+}
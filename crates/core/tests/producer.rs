@@ -1,6 +1,8 @@
+mod common;
+
 use std::sync::LazyLock;
 use vn_core::ProducerField::*;
-use vn_core::{ProducerId, ProducerType, SortProducerBy, Vndb};
+use vn_core::{ProducerId, ProducerType, SortProducerBy};
 
 const YUZUSOFT: &str = "Yuzusoft";
 
@@ -11,7 +13,7 @@ static YUZUSOFT_ID: LazyLock<ProducerId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_producer() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "p98"]"#;
   let mut results = vndb
     .post()
@@ -39,8 +41,8 @@ async fn get_producer() {
 
 #[tokio::test]
 async fn find_producer() {
-  let producer = Vndb::new()
-    .find_producer(&*YUZUSOFT_ID)
+  let producer = common::vndb()
+    .find_producer(&YUZUSOFT_ID)
     .fields([Name, Type])
     .send()
     .await
@@ -56,7 +58,7 @@ async fn find_producer() {
 
 #[tokio::test]
 async fn search_producer() {
-  let producer = Vndb::new()
+  let producer = common::vndb()
     .search_producer(YUZUSOFT.to_ascii_uppercase())
     .fields([Name, Type])
     .send()
@@ -0,0 +1,108 @@
+mod common;
+
+use common::{MockResponse, MockServer, stats};
+use futures::future::BoxFuture;
+use http::{HeaderMap, StatusCode};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use vn_core::Vndb;
+use vn_core::error::Result;
+use vn_core::http::{RateLimit, Transport, TransportRequest, TransportResponse};
+
+/// Answers every request after a round trip typical of a client far from the API.
+#[derive(Debug)]
+struct SlowTransport {
+  latency: Duration,
+}
+
+impl Transport for SlowTransport {
+  fn send(&self, _: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+    Box::pin(async move {
+      tokio::time::sleep(self.latency).await;
+      let body = json!({
+        "chars": 1,
+        "producers": 2,
+        "releases": 3,
+        "staff": 4,
+        "tags": 5,
+        "traits": 6,
+        "vn": 7,
+      });
+
+      Ok(TransportResponse {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: body.to_string().into_bytes(),
+      })
+    })
+  }
+}
+
+#[tokio::test]
+async fn rate_limit_window() {
+  let server = MockServer::start([stats(), stats(), stats()]).await;
+  let vndb = server
+    .builder()
+    .rate_limit(RateLimit::new(2, Duration::from_millis(300)))
+    .build();
+
+  let start = Instant::now();
+  for _ in 0..3 {
+    vndb.get().stats().await.unwrap();
+  }
+
+  assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test(start_paused = true)]
+async fn vndb_rate_limit_sustains_long_jobs() {
+  let vndb = Vndb::builder()
+    .transport(SlowTransport { latency: Duration::from_millis(300) })
+    .rate_limit(RateLimit::vndb())
+    .build();
+
+  let start = tokio::time::Instant::now();
+  let mut sent = 0;
+  while start.elapsed() < Duration::from_mins(10) {
+    vndb.get().stats().await.unwrap();
+    sent += 1;
+  }
+
+  // 200 requests every 5 minutes averages out to 40 a minute.
+  assert!(sent >= 400, "only {sent} requests were sent in 10 minutes");
+}
+
+#[tokio::test]
+async fn rate_limit_execution_time() {
+  let server = MockServer::start([stats(), stats()]).await;
+  let limit = RateLimit::new(100, Duration::from_mins(1))
+    .execution_time(Duration::from_nanos(1), Duration::from_millis(300));
+
+  let vndb = server.builder().rate_limit(limit).build();
+  let start = Instant::now();
+  for _ in 0..2 {
+    vndb.get().stats().await.unwrap();
+  }
+
+  assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+#[should_panic(expected = "at least one request")]
+fn rate_limit_rejects_zero_requests() {
+  let _ = RateLimit::new(0, Duration::from_mins(1));
+}
+
+#[tokio::test]
+async fn rate_limit_pauses_when_throttled() {
+  let server = MockServer::start([MockResponse::new(429, "Throttled."), stats()]).await;
+  let limit =
+    RateLimit::new(100, Duration::from_mins(1)).throttle_penalty(Duration::from_millis(300));
+
+  let vndb = server.builder().rate_limit(limit).build();
+  assert!(vndb.get().stats().await.is_err());
+
+  let start = Instant::now();
+  vndb.get().stats().await.unwrap();
+  assert!(start.elapsed() >= Duration::from_millis(300));
+}
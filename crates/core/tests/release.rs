@@ -1,6 +1,11 @@
+mod common;
+
 use std::sync::LazyLock;
 use vn_core::ReleaseField::*;
-use vn_core::{ReleaseId, Vndb};
+use vn_core::http::Equality;
+use vn_core::{
+  ExtlinkSite, ProducerField, ReleaseField, ReleaseFilter, ReleaseId, VisualNovelField,
+};
 
 const KUSARIHIME: &str = "Kusarihime ~Euthanasia~ Download Edition";
 
@@ -11,7 +16,7 @@ static KUSARIHIME_ID: LazyLock<ReleaseId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_release() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "r80"]"#;
   let results = vndb
     .post()
@@ -48,8 +53,8 @@ async fn get_release() {
 
 #[tokio::test]
 async fn find_release() {
-  let release = Vndb::new()
-    .find_release(&*KUSARIHIME_ID)
+  let release = common::vndb()
+    .find_release(&KUSARIHIME_ID)
     .fields([Title, AltTitle])
     .send()
     .await
@@ -64,7 +69,7 @@ async fn find_release() {
 
 #[tokio::test]
 async fn search_release() {
-  let results = Vndb::new()
+  let results = common::vndb()
     .search_release("Kusarihime")
     .fields([Title, AltTitle])
     .send()
@@ -85,3 +90,72 @@ async fn search_release() {
   assert_eq!(&release.id, &*KUSARIHIME_ID);
   assert_eq!(release.title.as_deref(), Some(KUSARIHIME));
 }
+
+#[tokio::test]
+async fn release_producers_and_visual_novels() {
+  let release = common::vndb()
+    .find_release(&KUSARIHIME_ID)
+    .fields(
+      ReleaseField::producers(ProducerField::Name)
+        .with(ReleaseField::vns(VisualNovelField::Title))
+        .with([Title, ProducersDeveloper, VisualNovelRType]),
+    )
+    .send()
+    .await
+    .unwrap()
+    .results
+    .pop_front()
+    .unwrap();
+
+  let producers = release.producers.unwrap();
+  assert!(!producers.is_empty());
+  assert!(
+    producers
+      .iter()
+      .all(|it| it.producer.name.is_some())
+  );
+
+  let vns = release.vns.unwrap();
+  assert!(!vns.is_empty());
+  assert!(
+    vns
+      .iter()
+      .all(|it| it.rtype.is_some() && it.visual_novel.title.is_some())
+  );
+}
+
+#[tokio::test]
+async fn find_release_by_extlink() {
+  let vndb = common::vndb();
+  let release = vndb
+    .post()
+    .release()
+    .filters(ReleaseFilter::Extlink(Equality::Eq, ExtlinkSite::Steam.into()).into())
+    .fields([ExtlinksName, ExtlinksId])
+    .results(1)
+    .send()
+    .await
+    .unwrap()
+    .results
+    .pop_front()
+    .unwrap();
+
+  let id = release
+    .extlinks
+    .unwrap()
+    .into_iter()
+    .find(|link| link.name == Some(ExtlinkSite::Steam))
+    .and_then(|link| link.id)
+    .unwrap();
+
+  assert!(id.is_number());
+
+  let found = vndb
+    .find_release_by_extlink(ExtlinkSite::Steam, id)
+    .send()
+    .await
+    .unwrap()
+    .results;
+
+  assert!(found.iter().any(|it| it.id == release.id));
+}
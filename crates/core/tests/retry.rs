@@ -0,0 +1,139 @@
+mod common;
+
+use common::{MockResponse, MockServer, stats};
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use vn_core::error::{Error, Result};
+use vn_core::http::{
+  ReqwestTransport, RetryPolicy, Transport, TransportRequest, TransportResponse,
+};
+
+#[tokio::test]
+async fn retry_on_server_error() {
+  let server = MockServer::start([
+    MockResponse::new(503, "Service Unavailable"),
+    MockResponse::new(429, "Throttled").header("Retry-After", "0"),
+    stats(),
+  ])
+  .await;
+
+  let retry = RetryPolicy::new()
+    .max_attempts(3)
+    .initial_backoff(Duration::from_millis(1));
+
+  let vndb = server.builder().retry(retry).build();
+  let stats = vndb.get().stats().await.unwrap();
+  assert_eq!(stats.vn, 7);
+  assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn retry_gives_up_after_max_attempts() {
+  let server = MockServer::start([
+    MockResponse::new(500, "Internal Server Error"),
+    MockResponse::new(500, "Internal Server Error"),
+    stats(),
+  ])
+  .await;
+
+  let retry = RetryPolicy::new()
+    .max_attempts(2)
+    .initial_backoff(Duration::from_millis(1));
+
+  let vndb = server.builder().retry(retry).build();
+  assert!(vndb.get().stats().await.is_err());
+  assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_by_max_backoff() {
+  let server = MockServer::start([
+    MockResponse::new(503, "Service Unavailable").header("Retry-After", "3600"),
+    MockResponse::new(503, "Service Unavailable")
+      .header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"),
+    stats(),
+  ])
+  .await;
+
+  let retry = RetryPolicy::new()
+    .max_attempts(3)
+    .max_backoff(Duration::from_millis(10));
+
+  let vndb = server.builder().retry(retry).build();
+  let start = Instant::now();
+  let stats = vndb.get().stats().await.unwrap();
+  assert_eq!(stats.vn, 7);
+  assert_eq!(server.requests().len(), 3);
+  assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+/// Fails the first `failures` requests as if the connection was refused.
+#[derive(Debug)]
+struct FlakyTransport {
+  failures: AtomicUsize,
+  inner: ReqwestTransport,
+}
+
+impl Transport for FlakyTransport {
+  fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+    let fail = self
+      .failures
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+      .is_ok();
+
+    if fail {
+      let reason = String::from("connection refused");
+      Box::pin(async move { Err(Error::ConnectionFailed { reason }) })
+    } else {
+      self.inner.send(request)
+    }
+  }
+}
+
+#[tokio::test]
+async fn retry_on_transport_error() {
+  let server = MockServer::start([stats()]).await;
+  let transport = FlakyTransport {
+    failures: AtomicUsize::new(1),
+    inner: ReqwestTransport::new().allow_http(true),
+  };
+
+  let retry = RetryPolicy::new()
+    .max_attempts(2)
+    .initial_backoff(Duration::from_millis(1));
+
+  let vndb = server
+    .builder()
+    .transport(transport)
+    .retry(retry)
+    .build();
+
+  let stats = vndb.get().stats().await.unwrap();
+  assert_eq!(stats.vn, 7);
+  assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn no_retry_on_client_error() {
+  let server = MockServer::start([MockResponse::new(400, "Invalid filter"), stats()]).await;
+  let vndb = server
+    .builder()
+    .retry(RetryPolicy::new())
+    .build();
+
+  assert!(vndb.get().stats().await.is_err());
+  assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn zero_max_attempts_never_retries() {
+  let server = MockServer::start([MockResponse::new(503, "Service Unavailable"), stats()]).await;
+  let retry = RetryPolicy::new()
+    .max_attempts(0)
+    .initial_backoff(Duration::from_millis(1));
+
+  let vndb = server.builder().retry(retry).build();
+  assert!(vndb.get().stats().await.is_err());
+  assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::{MockResponse, MockServer, auth_info};
+use serde_json::json;
+use vn_core::{ReleaseId, RlistStatus};
+
+#[tokio::test]
+async fn update_and_delete_release_list() {
+  let server = MockServer::start([
+    auth_info(&["listwrite"]),
+    MockResponse::new(204, ""),
+    MockResponse::new(204, ""),
+  ])
+  .await;
+
+  let vndb = server.builder().token("secret").build();
+  let id = ReleaseId::new("r1").unwrap();
+  vndb
+    .patch()
+    .rlist(&id, RlistStatus::Obtained)
+    .await
+    .unwrap();
+  vndb.delete().rlist(&id).await.unwrap();
+
+  let requests = server.requests();
+  assert_eq!(requests.len(), 3);
+  assert_eq!(requests[1].method, "PATCH");
+  assert_eq!(requests[1].path, "/kana/rlist/r1");
+  assert_eq!(requests[1].json(), json!({ "status": 2 }));
+  assert_eq!(requests[2].method, "DELETE");
+  assert_eq!(requests[2].path, "/kana/rlist/r1");
+}
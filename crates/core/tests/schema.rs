@@ -1,7 +1,145 @@
-use vn_core::Vndb;
+mod common;
+
+use serde_json::json;
+use strum::VariantArray;
+use vn_core::http::FieldSet;
+use vn_core::{
+  Endpoint, ExtlinkId, ExtlinkSite, Language, Medium, ReleaseField, Schema, VisualNovelField,
+};
+
+fn schema() -> Schema {
+  serde_json::from_value(json!({
+    "api_fields": {
+      "/vn": {
+        "id": null,
+        "title": null,
+        "image": { "url": null, "sexual": null },
+      },
+      "/release": {
+        "id": null,
+        "title": null,
+        "vns": { "rtype": null, "_inherit": "/vn" },
+      },
+    },
+    "enums": {
+      "language": [{ "id": "zh-Hant", "label": "Chinese (traditional)" }],
+      "medium": [{ "id": "cd", "label": "CD", "plural": "CDs" }],
+      "platform": [{ "id": "win", "label": "Windows" }],
+      "staff_role": [{ "id": "scenario", "label": "Scenario" }],
+    },
+    "extlinks": {
+      "/release": [
+        {
+          "name": "steam",
+          "label": "Steam",
+          "url_format": "https://store.steampowered.com/app/%d/",
+        },
+        {
+          "name": "toranoana",
+          "label": "Toranoana",
+          "url_format": "https://ec.toranoana.shop/tora/ec/item/%012d/",
+        },
+      ],
+    },
+  }))
+  .unwrap()
+}
+
+#[test]
+fn typed_schema() {
+  let schema = schema();
+  assert_eq!(schema.enums.language[0].id, Language::ChineseTraditional);
+  assert_eq!(schema.enums.medium[0].id, Medium::Cd);
+  assert_eq!(schema.enums.medium[0].plural.as_deref(), Some("CDs"));
+  assert_eq!(schema.extlink_sites(Endpoint::Release).len(), 2);
+  assert!(
+    schema
+      .extlink_sites(Endpoint::Staff)
+      .is_empty()
+  );
+}
+
+#[test]
+fn schema_field_paths() {
+  let schema = schema();
+  assert!(schema.is_valid_field(Endpoint::VisualNovel, "title"));
+  assert!(schema.is_valid_field(Endpoint::VisualNovel, "image.url"));
+  assert!(!schema.is_valid_field(Endpoint::VisualNovel, "image"));
+  assert!(!schema.is_valid_field(Endpoint::VisualNovel, "title.lang"));
+  assert!(!schema.is_valid_field(Endpoint::VisualNovel, "rtype"));
+  assert!(schema.is_valid_field(Endpoint::Release, "vns.rtype"));
+  assert!(schema.is_valid_field(Endpoint::Release, "vns.image.sexual"));
+  assert!(!schema.is_valid_field(Endpoint::Producer, "id"));
+
+  let fields = FieldSet::<ReleaseField>::from_raw(["title", "image", "vns.nope"].map(String::from));
+  assert_eq!(
+    schema.invalid_fields(Endpoint::Release, &fields),
+    ["image", "vns.nope"]
+  );
+}
+
+#[test]
+fn schema_extlink_urls() {
+  let schema = schema();
+  let steam = schema
+    .extlink(Endpoint::Release, &ExtlinkSite::Steam)
+    .unwrap();
+  assert_eq!(
+    steam
+      .url(&ExtlinkId::Number(412_830))
+      .unwrap(),
+    "https://store.steampowered.com/app/412830/"
+  );
+
+  let toranoana = schema
+    .extlink(Endpoint::Release, &ExtlinkSite::Toranoana)
+    .unwrap();
+  assert_eq!(
+    toranoana
+      .url(&ExtlinkId::Number(42))
+      .unwrap(),
+    "https://ec.toranoana.shop/tora/ec/item/000000000042/"
+  );
+
+  assert!(
+    schema
+      .extlink(Endpoint::Release, &ExtlinkSite::Gog)
+      .is_none()
+  );
+  assert!(
+    schema
+      .extlink(Endpoint::Staff, &ExtlinkSite::Steam)
+      .is_none()
+  );
+}
 
 #[tokio::test]
 async fn get_schema() {
-  let schema = Vndb::new().get().schema().await;
+  let schema = common::vndb().get().schema().await;
   assert!(schema.is_ok());
 }
+
+#[tokio::test]
+async fn schema_languages_are_known() {
+  let schema = common::vndb().get().schema().await.unwrap();
+  for language in schema.enums.language {
+    assert!(!language.id.is_unknown(), "{}", language.id);
+  }
+}
+
+#[tokio::test]
+async fn schema_knows_every_field() {
+  let schema = common::vndb().get().schema().await.unwrap();
+  for field in VisualNovelField::VARIANTS {
+    let field = field.to_string();
+    assert!(
+      schema.is_valid_field(Endpoint::VisualNovel, &field),
+      "{field}"
+    );
+  }
+
+  for field in ReleaseField::VARIANTS {
+    let field = field.to_string();
+    assert!(schema.is_valid_field(Endpoint::Release, &field), "{field}");
+  }
+}
@@ -0,0 +1,169 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use vn_core::{
+  AuthInfo, Character, CharacterSexValue, Language, ProducerType, Release, ReleaseImageType,
+  ReleaseVoiced, RlistStatus, StaffGender, TagCategory, TokenPermission, UlistLabels, VisualNovel,
+  VisualNovelDevStatus, VisualNovelLength,
+};
+
+/// Deserializes a response from the API, then checks that serializing and deserializing
+/// it again gives back the same thing.
+fn round_trip<T>(response: Value) -> T
+where
+  T: Serialize + DeserializeOwned,
+{
+  let first: T = serde_json::from_value(response).unwrap();
+  let serialized = serde_json::to_value(&first).unwrap();
+  let second: T = serde_json::from_value(serialized.clone()).unwrap();
+  assert_eq!(serde_json::to_value(&second).unwrap(), serialized);
+  second
+}
+
+#[test]
+fn visual_novel_round_trip() {
+  let visual_novel: VisualNovel = round_trip(json!({
+    "id": "v17",
+    "devstatus": 0,
+    "length": 4,
+    "olang": "ja",
+    "languages": ["en", "tlh"],
+    "released": "2002-08-29",
+  }));
+
+  assert_eq!(visual_novel.devstatus, Some(VisualNovelDevStatus::Finished));
+  assert_eq!(visual_novel.length, Some(VisualNovelLength::Long));
+  assert_eq!(
+    visual_novel.languages,
+    Some(vec![Language::English, Language::Unknown("tlh".to_owned())])
+  );
+}
+
+#[test]
+fn release_round_trip() {
+  let release: Release = round_trip(json!({
+    "id": "r1",
+    "voiced": 4,
+    "resolution": [800, 600],
+  }));
+
+  assert_eq!(release.voiced, Some(ReleaseVoiced::FullyVoiced));
+}
+
+#[test]
+fn character_round_trip() {
+  let character: Character = round_trip(json!({
+    "id": "c1",
+    "birthday": [2, 14],
+    "sex": ["f", null],
+    "gender": ["m", "f"],
+  }));
+
+  let birthday = character.birthday.unwrap();
+  assert_eq!((birthday.month, birthday.day), (2, 14));
+
+  let sex = character.sex.unwrap();
+  assert_eq!(sex.apparent, Some(CharacterSexValue::Female));
+  assert_eq!(sex.real, None);
+}
+
+#[test]
+fn user_list_labels_round_trip() {
+  let labels: UlistLabels = round_trip(json!({
+    "labels": [{ "id": 1, "label": "Playing", "private": false }],
+  }));
+
+  assert_eq!(labels.len(), 1);
+}
+
+#[test]
+fn numeric_enums_accept_codes_and_names() {
+  let status: RlistStatus = serde_json::from_value(json!(3)).unwrap();
+  assert_eq!(status, RlistStatus::OnLoan);
+  assert_eq!(serde_json::to_value(status).unwrap(), json!("OnLoan"));
+
+  let status: RlistStatus = serde_json::from_value(json!("OnLoan")).unwrap();
+  assert_eq!(status, RlistStatus::OnLoan);
+
+  let status: RlistStatus = serde_json::from_value(json!(9)).unwrap();
+  assert_eq!(status, RlistStatus::Other(9));
+  assert_eq!(status.code(), 9);
+  assert!(serde_json::from_value::<RlistStatus>(json!("Borrowed")).is_err());
+}
+
+#[test]
+fn unknown_values_are_kept() {
+  let visual_novel: VisualNovel = round_trip(json!({
+    "id": "v17",
+    "devstatus": 3,
+    "length": 6,
+  }));
+
+  assert_eq!(
+    visual_novel.devstatus,
+    Some(VisualNovelDevStatus::Unknown(3))
+  );
+  assert_eq!(visual_novel.length, Some(VisualNovelLength::Unknown(6)));
+  assert_eq!(visual_novel.length.unwrap().code(), 6);
+
+  let visual_novel: VisualNovel = round_trip(json!({ "id": "v17", "length": 300 }));
+  assert_eq!(visual_novel.length, Some(VisualNovelLength::Unknown(300)));
+  assert_eq!(visual_novel.length.unwrap().code(), 300);
+
+  let release: Release = round_trip(json!({
+    "id": "r1",
+    "voiced": 5,
+    "images": [{ "type": "pkgfront" }, { "type": "pkgtop" }],
+  }));
+
+  assert_eq!(release.voiced, Some(ReleaseVoiced::Unknown(5)));
+
+  let types: Vec<_> = release
+    .images
+    .unwrap()
+    .into_iter()
+    .map(|image| image.r#type.unwrap())
+    .collect();
+
+  assert_eq!(
+    types,
+    [
+      ReleaseImageType::PkgFront,
+      ReleaseImageType::Unknown("pkgtop".to_owned())
+    ]
+  );
+
+  let auth_info: AuthInfo = round_trip(json!({
+    "id": "u1",
+    "username": "yorhel",
+    "permissions": ["listread", "listdelete"],
+  }));
+
+  assert_eq!(
+    auth_info.permissions,
+    [
+      TokenPermission::ListRead,
+      TokenPermission::Unknown("listdelete".to_owned())
+    ]
+  );
+
+  assert_eq!(
+    serde_json::from_value::<ProducerType>(json!("xx")).unwrap(),
+    ProducerType::Unknown("xx".to_owned())
+  );
+  assert_eq!(
+    serde_json::from_value::<TagCategory>(json!("xx")).unwrap(),
+    TagCategory::Unknown("xx".to_owned())
+  );
+  assert_eq!(
+    serde_json::from_value::<StaffGender>(json!("xx")).unwrap(),
+    StaffGender::Unknown("xx".to_owned())
+  );
+  assert_eq!(
+    serde_json::from_value::<CharacterSexValue>(json!("xx")).unwrap(),
+    CharacterSexValue::Unknown("xx".to_owned())
+  );
+
+  let category: TagCategory = "xx".parse().unwrap();
+  assert_eq!(category.to_string(), "xx");
+}
@@ -1,6 +1,10 @@
+mod common;
+
+use serde_json::json;
 use std::sync::LazyLock;
 use vn_core::StaffField::*;
-use vn_core::{Language, StaffGender, StaffId, Vndb};
+use vn_core::http::Equality;
+use vn_core::{Language, StaffFilter, StaffGender, StaffId, StaffRole, VisualNovelStaff};
 
 const SUMIRE: &str = "Uesaka Sumire";
 
@@ -11,7 +15,7 @@ static SUMIRE_ID: LazyLock<StaffId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_staff() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "s4466"]"#;
   let results = vndb
     .post()
@@ -41,8 +45,8 @@ async fn get_staff() {
 
 #[tokio::test]
 async fn find_staff() {
-  let staff = Vndb::new()
-    .find_staff(&*SUMIRE_ID)
+  let staff = common::vndb()
+    .find_staff(&SUMIRE_ID)
     .fields([Name, Lang, Gender, IsMain])
     .send()
     .await
@@ -58,7 +62,7 @@ async fn find_staff() {
 
 #[tokio::test]
 async fn search_staff() {
-  let staff = Vndb::new()
+  let staff = common::vndb()
     .search_staff(SUMIRE.to_ascii_lowercase())
     .fields([Name, Lang, Gender, IsMain])
     .send()
@@ -72,3 +76,24 @@ async fn search_staff() {
   assert_eq!(staff.name.as_deref(), Some(SUMIRE));
   assert_eq!(staff.lang, Some(Language::Japanese));
 }
+
+#[test]
+fn staff_roles() {
+  let staff: VisualNovelStaff = serde_json::from_value(json!({
+    "id": "s1",
+    "role": "chardesign",
+  }))
+  .unwrap();
+
+  let role = staff.role.unwrap();
+  assert_eq!(role, StaffRole::CharacterDesign);
+  assert_eq!(role.name(), "Character design");
+  assert_eq!(StaffRole::Vocals.to_string(), "songs");
+  assert_eq!(
+    "voice".parse::<StaffRole>().unwrap(),
+    StaffRole::Unknown("voice".to_owned())
+  );
+
+  let filter = StaffFilter::Role(Equality::Eq, StaffRole::Composer);
+  assert_eq!(filter.to_json(), json!(["role", "=", "music"]));
+}
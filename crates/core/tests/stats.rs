@@ -1,7 +1,7 @@
-use vn_core::Vndb;
+mod common;
 
 #[tokio::test]
 async fn get_stats() {
-  let stats = Vndb::new().get().stats().await;
+  let stats = common::vndb().get().stats().await;
   assert!(stats.is_ok());
 }
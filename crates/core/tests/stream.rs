@@ -0,0 +1,80 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use futures::TryStreamExt;
+use serde_json::json;
+
+#[tokio::test]
+async fn stream_pages() {
+  let server = MockServer::start([
+    MockResponse::json(&json!({ "results": [{ "id": "v1" }, { "id": "v2" }], "more": true })),
+    MockResponse::json(&json!({ "results": [{ "id": "v3" }], "more": false })),
+  ])
+  .await;
+
+  let ids = server
+    .vndb()
+    .post()
+    .visual_novel()
+    .results(2)
+    .stream()
+    .map_ok(|visual_novel| visual_novel.id.to_string())
+    .try_collect::<Vec<_>>()
+    .await
+    .unwrap();
+
+  assert_eq!(ids, ["v1", "v2", "v3"]);
+
+  let requests = server.requests();
+  assert_eq!(requests.len(), 2);
+  assert_eq!(requests[0].json()["page"], 1);
+  assert_eq!(requests[1].json()["page"], 2);
+}
+
+#[tokio::test]
+async fn stream_limits() {
+  let server = MockServer::start([
+    MockResponse::json(&json!({ "results": [{ "id": "v1" }, { "id": "v2" }], "more": true })),
+    MockResponse::json(&json!({ "results": [{ "id": "v3" }, { "id": "v4" }], "more": true })),
+  ])
+  .await;
+
+  // The query only holds a weak handle, so the client must outlive it.
+  let vndb = server.vndb();
+  let query = vndb.post().visual_novel().results(2);
+
+  let items = query
+    .clone()
+    .max_items(1)
+    .stream()
+    .try_collect::<Vec<_>>()
+    .await
+    .unwrap();
+
+  assert_eq!(items.len(), 1);
+
+  let items = query
+    .clone()
+    .page(2)
+    .max_pages(1)
+    .stream()
+    .try_collect::<Vec<_>>()
+    .await
+    .unwrap();
+
+  assert_eq!(items.len(), 2);
+  assert_eq!(items[0].id.to_string(), "v3");
+
+  let items = query
+    .max_pages(0)
+    .stream()
+    .try_collect::<Vec<_>>()
+    .await
+    .unwrap();
+
+  assert!(items.is_empty());
+
+  let requests = server.requests();
+  assert_eq!(requests.len(), 2);
+  assert_eq!(requests[1].json()["page"], 2);
+}
@@ -1,6 +1,8 @@
+mod common;
+
 use std::sync::LazyLock;
 use vn_core::TagField::*;
-use vn_core::{TagCategory, TagId, Vndb};
+use vn_core::{TagCategory, TagId};
 
 const FANTASY: &str = "Medieval Fantasy";
 
@@ -11,7 +13,7 @@ static FANTASY_ID: LazyLock<TagId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_tag() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "g994"]"#;
   let mut results = vndb
     .post()
@@ -37,8 +39,8 @@ async fn get_tag() {
 
 #[tokio::test]
 async fn find_tag() {
-  let tag = Vndb::new()
-    .find_tag(&*FANTASY_ID)
+  let tag = common::vndb()
+    .find_tag(&FANTASY_ID)
     .fields([Name, Category])
     .send()
     .await
@@ -54,7 +56,7 @@ async fn find_tag() {
 
 #[tokio::test]
 async fn search_tag() {
-  let tag = Vndb::new()
+  let tag = common::vndb()
     .search_tag(FANTASY.to_ascii_lowercase())
     .fields([Name, Category])
     .send()
@@ -1,6 +1,8 @@
+mod common;
+
 use std::sync::LazyLock;
 use vn_core::TraitField::*;
-use vn_core::{TraitId, Vndb};
+use vn_core::TraitId;
 
 const AIRHEAD: &str = "Airhead";
 const AIRHEAD_GROUP: &str = "Personality";
@@ -12,7 +14,7 @@ static AIRHEAD_ID: LazyLock<TraitId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_trait() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "i229"]"#;
   let mut results = vndb
     .post()
@@ -38,8 +40,8 @@ async fn get_trait() {
 
 #[tokio::test]
 async fn find_trait() {
-  let r#trait = Vndb::new()
-    .find_trait(&*AIRHEAD_ID)
+  let r#trait = common::vndb()
+    .find_trait(&AIRHEAD_ID)
     .fields([Name, GroupName])
     .send()
     .await
@@ -55,7 +57,7 @@ async fn find_trait() {
 
 #[tokio::test]
 async fn search_trait() {
-  let r#trait = Vndb::new()
+  let r#trait = common::vndb()
     .search_trait(AIRHEAD.to_ascii_lowercase())
     .fields([Name, GroupName])
     .send()
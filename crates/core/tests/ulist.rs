@@ -0,0 +1,228 @@
+mod common;
+
+use common::{MockResponse, MockServer, auth_info};
+use serde_json::json;
+use vn_core::error::Error;
+use vn_core::{
+  ReleaseDate, RlistStatus, TokenPermission, UlistBuiltinLabel, UlistEntry, UlistField,
+  UlistLabelField, UlistSettableLabel, UlistUpdate, UserId, VisualNovelField, VisualNovelId, Vote,
+};
+
+#[tokio::test]
+async fn user_list_entries() {
+  let server = MockServer::start([MockResponse::json(&json!({
+    "results": [{
+      "id": "v17",
+      "added": 1_700_000_000,
+      "vote": 85,
+      "labels": [{ "id": 2, "label": "Finished" }],
+      "finished": "2024-01-31",
+      "vn": { "title": "Ever17" },
+    }],
+    "more": false,
+  }))])
+  .await;
+
+  let user = UserId::new("u1").unwrap();
+  let entry = server
+    .vndb()
+    .user_list(&user)
+    .fields(
+      UlistField::vn(VisualNovelField::Title).with([UlistField::Vote, UlistField::LabelsLabel]),
+    )
+    .send()
+    .await
+    .unwrap()
+    .results
+    .pop_front()
+    .unwrap();
+
+  assert_eq!(entry.id.to_string(), "v17");
+  assert_eq!(entry.vote, Vote::new(85));
+  assert_eq!(entry.finished, ReleaseDate::from_ymd(2024, 1, 31));
+  assert_eq!(entry.started, None);
+  assert_eq!(entry.labels.unwrap()[0].label.as_deref(), Some("Finished"));
+
+  let vn = entry.vn.unwrap();
+  assert_eq!(vn.id, entry.id);
+  assert_eq!(vn.title.as_deref(), Some("Ever17"));
+
+  let requests = server.requests();
+  assert_eq!(requests[0].path, "/kana/ulist");
+  assert_eq!(requests[0].json()["user"], "u1");
+
+  let fields = requests[0].json()["fields"].to_string();
+  assert!(fields.contains("vn.title"));
+}
+
+#[test]
+fn user_list_release_status() {
+  let entry: UlistEntry = serde_json::from_value(json!({
+    "id": "v17",
+    "releases": [
+      { "id": "r1", "list_status": 2, "title": "Ever17" },
+      { "id": "r2", "list_status": 3 },
+    ],
+  }))
+  .unwrap();
+
+  let releases = entry.releases.unwrap();
+  assert_eq!(releases[0].list_status, Some(RlistStatus::Obtained));
+  assert_eq!(releases[0].release.title.as_deref(), Some("Ever17"));
+  assert_eq!(releases[1].list_status, Some(RlistStatus::OnLoan));
+  assert_eq!(releases[1].release.id.to_string(), "r2");
+}
+
+#[tokio::test]
+async fn user_list_labels() {
+  let server = MockServer::start([MockResponse::json(&json!({
+    "labels": [
+      { "id": 1, "label": "Playing", "private": false, "count": 3 },
+      { "id": 5, "label": "Wishlist", "private": true, "count": 12 },
+      { "id": 10, "label": "Replaying", "private": false, "count": 1 },
+    ],
+  }))])
+  .await;
+
+  let user = UserId::new("u1").unwrap();
+  let labels = server
+    .vndb()
+    .get()
+    .ulist_labels(&user, UlistLabelField::Count)
+    .await
+    .unwrap();
+
+  assert_eq!(labels.len(), 3);
+  assert_eq!(labels[0].builtin(), Some(UlistBuiltinLabel::Playing));
+  assert!(labels[2].is_custom());
+
+  let wishlist = labels
+    .builtin(UlistBuiltinLabel::Wishlist)
+    .unwrap();
+  assert!(wishlist.private);
+  assert_eq!(wishlist.count, Some(12));
+
+  let requests = server.requests();
+  assert_eq!(requests[0].path, "/kana/ulist_labels?user=u1&fields=count");
+}
+
+#[test]
+fn votes_out_of_range_are_rejected() {
+  assert_eq!(Vote::new(10).map(Vote::get), Some(10));
+  assert_eq!(Vote::new(100).map(Vote::get), Some(100));
+  assert!(Vote::new(9).is_none());
+  assert!(matches!(Vote::try_from(101), Err(Error::InvalidVote(101))));
+}
+
+#[test]
+fn list_dates_must_be_full() {
+  let update = UlistUpdate::new();
+  assert!(
+    update
+      .clone()
+      .started(ReleaseDate::from_ymd(2019, 4, 26).unwrap())
+      .is_ok()
+  );
+
+  for date in [ReleaseDate::TBA, ReleaseDate::from_ym(2019, 4).unwrap()] {
+    assert!(matches!(
+      update.clone().finished(date),
+      Err(Error::InvalidReleaseDate(_))
+    ));
+  }
+}
+
+#[test]
+fn voted_label_is_read_only() {
+  let label = UlistSettableLabel::try_from(UlistBuiltinLabel::Finished).unwrap();
+  assert_eq!(UlistBuiltinLabel::from(label), UlistBuiltinLabel::Finished);
+
+  let voted = UlistSettableLabel::try_from(UlistBuiltinLabel::Voted);
+  assert!(matches!(
+    voted,
+    Err(Error::ReadOnlyLabel(UlistBuiltinLabel::Voted))
+  ));
+
+  assert!(
+    UlistUpdate::new()
+      .labels_set([1, 10])
+      .is_ok()
+  );
+  assert!(matches!(
+    UlistUpdate::new().labels_set([2, 7]),
+    Err(Error::ReadOnlyLabel(UlistBuiltinLabel::Voted))
+  ));
+  assert!(matches!(
+    UlistUpdate::new().labels_unset([7]),
+    Err(Error::ReadOnlyLabel(UlistBuiltinLabel::Voted))
+  ));
+}
+
+#[tokio::test]
+async fn update_and_delete_user_list() {
+  let server = MockServer::start([
+    auth_info(&["listread", "listwrite"]),
+    MockResponse::new(204, ""),
+    MockResponse::new(204, ""),
+  ])
+  .await;
+
+  let vndb = server.builder().token("secret").build();
+  let id = VisualNovelId::new("v17").unwrap();
+  let update = UlistUpdate::new()
+    .vote(Vote::new(90).unwrap())
+    .remove_notes()
+    .finished(ReleaseDate::from_ymd(2024, 1, 31).unwrap())
+    .unwrap()
+    .set_label(UlistSettableLabel::Finished)
+    .unset_label(UlistSettableLabel::Playing);
+
+  vndb
+    .patch()
+    .ulist(&id, &update)
+    .await
+    .unwrap();
+  vndb.delete().ulist(&id).await.unwrap();
+
+  let requests = server.requests();
+  assert_eq!(requests.len(), 3);
+  assert_eq!(requests[0].path, "/kana/authinfo");
+  assert_eq!(requests[1].method, "PATCH");
+  assert_eq!(requests[1].path, "/kana/ulist/v17");
+  assert_eq!(
+    requests[1].json(),
+    json!({
+      "vote": 90,
+      "notes": null,
+      "finished": "2024-01-31",
+      "labels_set": [2],
+      "labels_unset": [1],
+    })
+  );
+
+  assert_eq!(requests[2].method, "DELETE");
+  assert_eq!(requests[2].path, "/kana/ulist/v17");
+  assert_eq!(requests[2].header("Authorization"), Some("Token secret"));
+}
+
+#[tokio::test]
+async fn user_list_writes_require_permission() {
+  let server = MockServer::start([auth_info(&["listread"])]).await;
+  let vndb = server.builder().token("secret").build();
+
+  let id = VisualNovelId::new("v17").unwrap();
+  let error = vndb.delete().ulist(&id).await.unwrap_err();
+  assert!(matches!(
+    error,
+    Error::MissingPermission(TokenPermission::ListWrite)
+  ));
+
+  let error = server
+    .vndb()
+    .delete()
+    .ulist(&id)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, Error::Unauthorized));
+  assert_eq!(server.requests().len(), 1);
+}
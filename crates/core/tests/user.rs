@@ -1,9 +1,11 @@
+mod common;
+
 use vn_core::UserField::{self, *};
-use vn_core::{UserId, Vndb};
+use vn_core::UserId;
 
 #[tokio::test]
 async fn get_user() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let users = vndb
     .get()
     .user(500, UserField::all())
@@ -29,7 +31,7 @@ async fn get_user() {
 
 #[tokio::test]
 async fn get_user_range() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let users = vndb
     .get()
     .user(500..510, UserField::none())
@@ -66,7 +68,7 @@ async fn get_user_range() {
 #[tokio::test]
 async fn find_user() {
   let id = UserId::new("u500").unwrap();
-  let user = Vndb::new()
+  let user = common::vndb()
     .find_user(&id)
     .await
     .unwrap()
@@ -1,6 +1,13 @@
+mod common;
+
+use serde_json::json;
 use std::sync::LazyLock;
 use vn_core::VisualNovelField::*;
-use vn_core::{VisualNovelId, Vndb};
+use vn_core::http::FieldSet;
+use vn_core::{
+  CharacterField, RelationType, ReleaseField, StaffField, VisualNovelField, VisualNovelId,
+  VisualNovelRelation,
+};
 
 const NOVEL: &str = "Yosuga no Sora";
 const NOVEL_JP: &str = "ヨスガノソラ";
@@ -12,7 +19,7 @@ static NOVEL_ID: LazyLock<VisualNovelId> = LazyLock::new(|| {
 
 #[tokio::test]
 async fn get_visual_novel() {
-  let vndb = Vndb::new();
+  let vndb = common::vndb();
   let filters = r#"["id", "=", "v1194"]"#;
   let visual_novel = vndb
     .post()
@@ -53,7 +60,7 @@ async fn get_visual_novel() {
 
 #[tokio::test]
 async fn find_visual_novel() {
-  let visual_novel = Vndb::new()
+  let visual_novel = common::vndb()
     .find_visual_novel(&NOVEL_ID)
     .fields(Title)
     .send()
@@ -69,7 +76,7 @@ async fn find_visual_novel() {
 
 #[tokio::test]
 async fn search_visual_novel() {
-  let visual_novel = Vndb::new()
+  let visual_novel = common::vndb()
     .search_visual_novel(NOVEL.to_ascii_lowercase())
     .fields(Title)
     .send()
@@ -82,3 +89,83 @@ async fn search_visual_novel() {
   assert_eq!(visual_novel.id, *NOVEL_ID);
   assert_eq!(visual_novel.title.as_deref(), Some(NOVEL));
 }
+
+#[test]
+fn nested_field_paths() {
+  let fields = VisualNovelField::va_character(
+    FieldSet::from(CharacterField::Name).with(CharacterField::vns_release([ReleaseField::Title])),
+  )
+  .with(VisualNovelField::va_staff(StaffField::Name))
+  .with(Title);
+
+  assert_eq!(
+    fields.join(),
+    [
+      "title",
+      "va.character.id",
+      "va.character.name",
+      "va.character.vns.id",
+      "va.character.vns.release.id",
+      "va.character.vns.release.title",
+      "va.staff.id",
+      "va.staff.name",
+    ]
+    .join(",")
+  );
+}
+
+#[test]
+fn relation_fields_are_not_nested_again() {
+  let fields = VisualNovelField::relations([Title, ImageUrl, VaCharacterId, RelationsRelation]);
+  assert_eq!(
+    fields.join(),
+    ["relations.id", "relations.image.url", "relations.title"].join(",")
+  );
+}
+
+#[tokio::test]
+async fn nested_voice_actors() {
+  let visual_novel = common::vndb()
+    .find_visual_novel(&NOVEL_ID)
+    .fields(VisualNovelField::va_character(CharacterField::Name))
+    .fields(VisualNovelField::va_staff(StaffField::Name))
+    .send()
+    .await
+    .unwrap()
+    .results
+    .pop_front()
+    .unwrap();
+
+  let va = visual_novel.va.unwrap();
+  assert!(!va.is_empty());
+  assert!(va.iter().all(|it| {
+    it.character
+      .as_ref()
+      .is_some_and(|character| character.name.is_some())
+      && it
+        .staff
+        .as_ref()
+        .is_some_and(|staff| staff.name.is_some())
+  }));
+}
+
+#[test]
+fn relation_types() {
+  let relation: VisualNovelRelation = serde_json::from_value(json!({
+    "id": "v2",
+    "relation": "seq",
+    "relation_official": true,
+  }))
+  .unwrap();
+
+  let kind = relation.relation.unwrap();
+  assert_eq!(kind, RelationType::Sequel);
+  assert_eq!(kind.name(), "Sequel");
+  assert_eq!(kind.inverse(), RelationType::Prequel);
+  assert_eq!(RelationType::SideStory.inverse(), RelationType::Parent);
+  assert_eq!(RelationType::SameSeries.inverse(), RelationType::SameSeries);
+
+  let unknown = RelationType::Unknown("remake".to_owned());
+  assert_eq!(unknown.inverse(), unknown);
+  assert_eq!(serde_json::to_value(&unknown).unwrap(), json!("remake"));
+}